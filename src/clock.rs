@@ -0,0 +1,60 @@
+// src/clock.rs
+
+use chrono::{DateTime, Utc};
+
+use crate::{DecimalDuration, DecimalTime};
+
+/// A source of the current time.
+///
+/// The trait is object-safe, so a clock can be chosen at runtime and passed
+/// around as `&dyn Clock` (e.g. an RTC-backed clock on embedded targets,
+/// or a fixed clock in tests).
+pub trait Clock {
+    /// Returns the current instant in UTC.
+    fn now_utc(&self) -> DateTime<Utc>;
+}
+
+/// The system wall clock, backed by `chrono::Utc::now()`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now_utc(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+impl DecimalTime {
+    /// Returns the current UTC time as a `DecimalTime`.
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        Self::now_with_dyn_clock(&SystemClock)
+    }
+
+    /// Returns the current UTC time as reported by `clock`.
+    pub fn now_with_dyn_clock(clock: &dyn Clock) -> Self {
+        Self::from_datetime_utc(clock.now_utc())
+    }
+
+    /// Returns the signed duration from `self` to now, like
+    /// `std::time::Instant::elapsed` (negative if `self` is in the future).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+    #[cfg(feature = "std")]
+    pub fn elapsed(&self) -> DecimalDuration {
+        self.elapsed_with_dyn_clock(&SystemClock)
+    }
+
+    /// Like `elapsed`, but reads "now" from `clock`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+    pub fn elapsed_with_dyn_clock(&self, clock: &dyn Clock) -> DecimalDuration {
+        Self::now_with_dyn_clock(clock).signed_duration_since(self)
+    }
+}
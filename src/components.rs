@@ -0,0 +1,24 @@
+// src/components.rs
+
+/// A `DecimalTime` broken down into decimal clock units, alongside the
+/// equivalent conventional wall-clock time.
+///
+/// The decimal clock has 10 hours per day, 100 minutes per hour
+/// and 100 seconds per minute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecimalComponents {
+    pub year: i32,
+    pub day_of_year: u32,
+    /// Decimal hour in 0..=9.
+    pub decimal_hour: u32,
+    /// Decimal minute in 0..=99.
+    pub decimal_minute: u32,
+    /// Decimal second in [0.0, 100.0), including any sub-second fraction.
+    pub decimal_second: f64,
+    /// Conventional hour in 0..=23.
+    pub hour: u32,
+    /// Conventional minute in 0..=59.
+    pub minute: u32,
+    /// Conventional second in 0..=59.
+    pub second: u32,
+}
@@ -0,0 +1,74 @@
+// src/date.rs
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{math, validate, DecimalTime, DecimalTimeError};
+
+/// A calendar day in decimal time terms: a year and a 1-based day of year,
+/// without a time of day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DecimalDate {
+    pub year: i32,
+    pub day_of_year: u32,
+}
+
+impl DecimalDate {
+    /// Creates a new `DecimalDate`, validating `day_of_year` against the year's length.
+    pub fn new(year: i32, day_of_year: u32) -> Result<Self, DecimalTimeError> {
+        validate(year, day_of_year, 0.0)?;
+        Ok(DecimalDate { year, day_of_year })
+    }
+
+    /// Returns the `DecimalTime` at fraction `decimal_day` of this day.
+    ///
+    /// Fails if `decimal_day` is out of [0,1) or the date is invalid.
+    pub fn at(&self, decimal_day: f64) -> Result<DecimalTime, DecimalTimeError> {
+        validate(self.year, self.day_of_year, decimal_day)?;
+        Ok(DecimalTime::new_unchecked(self.year, self.day_of_year, decimal_day))
+    }
+
+    /// Returns the `DecimalTime` at fraction `frac` of this day, carrying whole
+    /// days (and years) for fractions outside [0,1) instead of failing.
+    ///
+    /// For example `2.25` lands at 0.25 two days later and `-0.25` at 0.75
+    /// on the previous day.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frac` is not finite, the date is invalid, or the result
+    /// is out of chrono's range.
+    pub fn with_fraction_wrapping(&self, frac: f64) -> DecimalTime {
+        if !frac.is_finite() {
+            panic!("`frac` must be finite. Received: {}", frac);
+        }
+
+        let mut whole_days = math::floor(frac);
+        let mut decimal_day = frac - whole_days;
+        if decimal_day >= 1.0 {
+            // `frac` was a tiny negative number that rounded up to a full day
+            whole_days += 1.0;
+            decimal_day = 0.0;
+        }
+
+        let date = NaiveDate::from_yo_opt(self.year, self.day_of_year)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Invalid day_of_year={} for year={}",
+                    self.day_of_year, self.year
+                )
+            })
+            .checked_add_signed(chrono::Duration::days(whole_days as i64))
+            .expect("wrapped date is out of range");
+        DecimalTime::new_unchecked(date.year(), date.ordinal(), decimal_day)
+    }
+}
+
+impl DecimalTime {
+    /// Returns the calendar day of this time, dropping `decimal_day`.
+    pub fn date(&self) -> DecimalDate {
+        DecimalDate {
+            year: self.year,
+            day_of_year: self.day_of_year,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+// src/duration.rs
+
+use core::fmt;
+use core::ops::{Add, Sub};
+
+use crate::{math, DecimalTime, MICROS_PER_DAY};
+
+/// A signed span of time measured in decimal days.
+///
+/// Stored as a whole number of microseconds so that spans add up exactly,
+/// matching the microsecond precision of the chrono conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DecimalDuration {
+    micros: i64,
+}
+
+impl DecimalDuration {
+    /// A zero-length duration.
+    pub const ZERO: DecimalDuration = DecimalDuration { micros: 0 };
+
+    /// Creates a duration from a (possibly fractional) number of decimal days,
+    /// rounded to the nearest microsecond.
+    pub fn from_decimal_days(days: f64) -> Self {
+        DecimalDuration {
+            micros: math::round(days * MICROS_PER_DAY as f64) as i64,
+        }
+    }
+
+    /// Creates a duration from a whole number of microseconds.
+    pub fn from_microseconds(micros: i64) -> Self {
+        DecimalDuration { micros }
+    }
+
+    /// Returns the duration in decimal days.
+    pub fn as_decimal_days(&self) -> f64 {
+        self.micros as f64 / MICROS_PER_DAY as f64
+    }
+
+    /// Returns the duration in whole microseconds.
+    pub fn num_microseconds(&self) -> i64 {
+        self.micros
+    }
+
+    /// Converts to a `chrono::Duration`.
+    pub fn to_chrono(&self) -> chrono::Duration {
+        chrono::Duration::microseconds(self.micros)
+    }
+}
+
+/// Renders the duration in decimal days, e.g. `1d` or `0.25d`.
+impl fmt::Display for DecimalDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}d", self.as_decimal_days())
+    }
+}
+
+/// Adds a duration, carrying across day and year boundaries.
+///
+/// Saturates at `DecimalTime::MIN`/`MAX` instead of overflowing.
+///
+/// # Panics
+///
+/// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+impl Add<DecimalDuration> for DecimalTime {
+    type Output = DecimalTime;
+
+    fn add(self, rhs: DecimalDuration) -> DecimalTime {
+        self.saturating_add(rhs.to_chrono())
+    }
+}
+
+/// Subtracts a duration, carrying across day and year boundaries.
+///
+/// Saturates at `DecimalTime::MIN`/`MAX` instead of overflowing.
+///
+/// # Panics
+///
+/// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+impl Sub<DecimalDuration> for DecimalTime {
+    type Output = DecimalTime;
+
+    fn sub(self, rhs: DecimalDuration) -> DecimalTime {
+        self.saturating_sub(rhs.to_chrono())
+    }
+}
+
+/// The signed duration between two times, same as `signed_duration_since`.
+impl Sub<DecimalTime> for DecimalTime {
+    type Output = DecimalDuration;
+
+    fn sub(self, rhs: DecimalTime) -> DecimalDuration {
+        self.signed_duration_since(&rhs)
+    }
+}
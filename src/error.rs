@@ -0,0 +1,103 @@
+// src/error.rs
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Errors returned by the fallible `DecimalTime` constructors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecimalTimeError {
+    /// `decimal_day` was outside [0,1).
+    DecimalDayOutOfRange(f64),
+    /// `day_of_year` was 0 or past the last day of `year`.
+    DayOfYearOutOfRange { year: i32, day_of_year: u32 },
+    /// `year` was outside `DecimalTime::MIN_YEAR..=DecimalTime::MAX_YEAR`.
+    YearOutOfRange(i32),
+    /// A decimal clock component (hour, minute or second) was out of range.
+    ComponentOutOfRange { component: &'static str, value: f64 },
+}
+
+impl fmt::Display for DecimalTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecimalTimeError::DecimalDayOutOfRange(value) => {
+                write!(f, "`decimal_day` must be in [0,1). Received: {}", value)
+            }
+            DecimalTimeError::DayOfYearOutOfRange { year, day_of_year } => write!(
+                f,
+                "`day_of_year` must be in [1..={}] for year {}. Received: {}",
+                crate::days_in_year(*year),
+                year,
+                day_of_year
+            ),
+            DecimalTimeError::YearOutOfRange(year) => write!(
+                f,
+                "`year` must be in [{}..={}]. Received: {}",
+                crate::DecimalTime::MIN_YEAR,
+                crate::DecimalTime::MAX_YEAR,
+                year
+            ),
+            DecimalTimeError::ComponentOutOfRange { component, value } => {
+                write!(f, "decimal {} out of range. Received: {}", component, value)
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecimalTimeError {}
+
+/// Errors returned by `DecimalTime::try_format` for an invalid format string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatError {
+    /// `%` was followed by a character that is not a known specifier.
+    UnknownSpecifier { specifier: char, position: usize },
+    /// The format string ended with a lone `%`.
+    TrailingPercent,
+    /// chrono rejected the format string passed to `strftime_date`.
+    InvalidStrftime,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::UnknownSpecifier { specifier, position } => {
+                write!(f, "unknown format specifier %{} at position {}", specifier, position)
+            }
+            FormatError::TrailingPercent => write!(f, "format string ends with a lone %"),
+            FormatError::InvalidStrftime => write!(f, "invalid chrono strftime format string"),
+        }
+    }
+}
+
+impl core::error::Error for FormatError {}
+
+/// Errors returned when parsing a `DecimalTime` from text.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The input did not have the expected shape.
+    Malformed(String),
+    /// The input was well-formed but a field was out of range.
+    OutOfRange(DecimalTimeError),
+}
+
+#[cfg(feature = "alloc")]
+impl From<DecimalTimeError> for ParseError {
+    fn from(err: DecimalTimeError) -> Self {
+        ParseError::OutOfRange(err)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Malformed(reason) => write!(f, "malformed input: {}", reason),
+            ParseError::OutOfRange(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for ParseError {}
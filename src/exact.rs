@@ -0,0 +1,85 @@
+// src/exact.rs
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+
+use crate::{validate, DecimalTime, DecimalTimeError, MICROS_PER_DAY};
+
+/// A `DecimalTime` that stores the time of day as a whole number of
+/// microseconds instead of an `f64` fraction.
+///
+/// Conversions to and from chrono are lossless at microsecond precision,
+/// and equality and ordering are exact. The fraction of the day is
+/// computed on demand by `decimal_day()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DecimalTimeExact {
+    year: i32,
+    day_of_year: u32,
+    micros: u64,
+}
+
+impl DecimalTimeExact {
+    /// Creates a new `DecimalTimeExact` from the microsecond of the day.
+    ///
+    /// Fails if `day_of_year` is out of range for `year` or `micros` is
+    /// a full day or more.
+    pub fn new(year: i32, day_of_year: u32, micros: u64) -> Result<Self, DecimalTimeError> {
+        if micros >= MICROS_PER_DAY as u64 {
+            return Err(DecimalTimeError::DecimalDayOutOfRange(micros as f64 / MICROS_PER_DAY as f64));
+        }
+        validate(year, day_of_year, 0.0)?;
+        Ok(DecimalTimeExact { year, day_of_year, micros })
+    }
+
+    /// The year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The 1-based day of the year.
+    pub fn day_of_year(&self) -> u32 {
+        self.day_of_year
+    }
+
+    /// The microsecond of the day, in `0..86_400_000_000`.
+    pub fn microseconds_into_day(&self) -> u64 {
+        self.micros
+    }
+
+    /// The fraction of the day elapsed, in [0,1).
+    pub fn decimal_day(&self) -> f64 {
+        self.micros as f64 / MICROS_PER_DAY as f64
+    }
+
+    /// Converts a `chrono::NaiveDateTime`, truncating to microseconds.
+    pub fn from_naive_datetime(dt: NaiveDateTime) -> Self {
+        let micros = dt.num_seconds_from_midnight() as u64 * 1_000_000 + (dt.nanosecond() / 1_000) as u64;
+        DecimalTimeExact {
+            year: dt.year(),
+            day_of_year: dt.ordinal(),
+            // leap seconds are folded into the last microsecond of the day
+            micros: micros.min(MICROS_PER_DAY as u64 - 1),
+        }
+    }
+
+    /// Converts into a `chrono::NaiveDateTime` without any rounding.
+    pub fn to_naive_datetime(&self) -> NaiveDateTime {
+        NaiveDate::from_yo_opt(self.year, self.day_of_year)
+            .expect("validated on construction")
+            .and_hms_micro_opt(0, 0, 0, 0)
+            .unwrap() // safe as it’s midnight
+            + chrono::Duration::microseconds(self.micros as i64)
+    }
+
+    /// Converts a `DecimalTime`, rounding `decimal_day` to the nearest
+    /// microsecond (and carrying into the next day if it rounds up to one).
+    ///
+    /// Returns `None` if `t` is invalid or the result is out of range.
+    pub fn from_decimal_time(t: &DecimalTime) -> Option<Self> {
+        t.to_naive_datetime_opt().map(Self::from_naive_datetime)
+    }
+
+    /// Converts into a float-based `DecimalTime`.
+    pub fn to_decimal_time(&self) -> DecimalTime {
+        DecimalTime::new_unchecked(self.year, self.day_of_year, self.decimal_day())
+    }
+}
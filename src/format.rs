@@ -0,0 +1,711 @@
+// src/format.rs
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use core::fmt::{self, Write};
+
+use chrono::Datelike;
+
+use crate::{DecimalTime, FormatError, RoundingMode};
+#[cfg(feature = "alloc")]
+use crate::ParseError;
+
+impl DecimalTime {
+    /// Format `DecimalTime` with simple placeholders:
+    /// - `%Y` => year
+    /// - `%'Y` => year with thousands separators (e.g. `1,000,000`)
+    /// - `%d` => day_of_year (3-digit zero-padded)
+    /// - `%j` => day_of_year (not padded)
+    /// - `%f` => fraction of day
+    /// - `%.Nf` => fraction of day to `N` places, rounded but never up to
+    ///   a full day (e.g. `%.3f` => `.500`)
+    /// - `%0NY` / `%0Nd` / `%0Nj` => year or day_of_year zero-padded to
+    ///   `N` digits (e.g. `%03j` => `073`); `N` in `%.Nf`, `%.Np` and these
+    ///   is at most 99
+    /// - `%r` => conventional `HH:MM:SS` into the day (e.g. `12:00:00` for 0.5)
+    /// - `%T` => same as `%r`, the 24-hour `HH:MM:SS` wall-clock time
+    /// - `%R` => 24-hour `HH:MM` wall-clock time (e.g. `12:00`)
+    /// - `%w` => abbreviated Gregorian weekday (e.g. `Fri`)
+    /// - `%c` => Gregorian calendar date as chrono's `%Y-%m-%d` (e.g. `2025-03-14`)
+    /// - `%p` => percentage of the day elapsed (e.g. `50%` for 0.5)
+    /// - `%.Np` => the same with `N` decimal places (e.g. `%.1p` => `50.0%`)
+    /// - `%%` => a literal `%`
+    ///
+    /// The format string is scanned once, left to right, so substituted
+    /// values are never re-read as tokens. Unknown specifiers are copied
+    /// through unchanged; use `try_format` to reject them instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fmt_str` contains `%w` or `%c` and the date is invalid.
+    ///
+    /// # Example
+    /// 
+    /// ```
+    /// let dec = decimal_time::DecimalTime::new(2025, 5, 0.5);
+    /// let s = dec.format("Year=%Y Day=%d (%j) Fraction=%f");
+    /// assert_eq!(s, "Year=2025 Day=005 (5) Fraction=.5");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format(&self, fmt_str: &str) -> String {
+        let mut output = String::with_capacity(fmt_str.len());
+        self.format_into(&mut output, fmt_str)
+            .expect("writing to a String cannot fail");
+        output
+    }
+
+    /// Like `format`, but writes straight into `w` instead of allocating
+    /// a `String`, e.g. to reuse one buffer across many calls.
+    ///
+    /// Fails only if `w` does. Available without the `alloc` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::fmt::Write;
+    ///
+    /// let mut buffer = String::new();
+    /// for day in [73, 74] {
+    ///     buffer.clear();
+    ///     decimal_time::DecimalTime::new(2025, day, 0.5).format_into(&mut buffer, "%Y-%d%f").unwrap();
+    ///     assert!(buffer.ends_with(".5"));
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fmt_str` contains `%w` or `%c` and the date is invalid.
+    pub fn format_into<W: Write + ?Sized>(&self, w: &mut W, fmt_str: &str) -> fmt::Result {
+        self.write_formatted(w, fmt_str, None, false, ',')
+            .map_err(|_| fmt::Error)
+    }
+
+    /// Like `format`, but returns a value that formats lazily when
+    /// displayed, writing straight into the destination without an
+    /// intermediate `String`. Available without the `alloc` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let dec = decimal_time::DecimalTime::new(2025, 73, 0.5);
+    /// assert_eq!(format!("at {}", dec.format_lazy("%Y-%d%f")), "at 2025-073.5");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Displaying it panics if `fmt_str` contains `%w` or `%c` and the
+    /// date is invalid.
+    pub fn format_lazy<'a>(&'a self, fmt_str: &'a str) -> DecimalTimeFormat<'a> {
+        DecimalTimeFormat { time: self, fmt_str }
+    }
+
+    /// Like `format`, but rejects unknown specifiers (and a trailing lone `%`)
+    /// instead of copying them through.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use decimal_time::{DecimalTime, FormatError};
+    ///
+    /// let dec = DecimalTime::new(2025, 73, 0.5);
+    /// assert_eq!(dec.try_format("%Y-%d 100%%"), Ok("2025-073 100%".to_string()));
+    /// assert_eq!(
+    ///     dec.try_format("%Y-%q"),
+    ///     Err(FormatError::UnknownSpecifier { specifier: 'q', position: 3 })
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn try_format(&self, fmt_str: &str) -> Result<String, FormatError> {
+        self.formatted_string(fmt_str, None, true, ',')
+    }
+
+    /// Like `format`, but also expands `%e` to the decimal days elapsed
+    /// since `epoch` (see `to_days_since`), e.g. `"1"` one day after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fmt_str` contains `%e` and either date is invalid.
+    #[cfg(feature = "alloc")]
+    pub fn format_since(&self, epoch: &DecimalTime, fmt_str: &str) -> String {
+        self.formatted_string(fmt_str, Some(epoch), false, ',')
+            .expect("lenient formatting never fails")
+    }
+
+    /// Describes `self` relative to `reference` in decimal units, e.g.
+    /// `"0.25 decimal days ago"` or `"in 3 décades"`.
+    ///
+    /// The unit is picked from the magnitude of the signed duration:
+    /// - under 0.005 days (`0.00` to two places) => `"now"`
+    /// - under 10 days => decimal days, to two places
+    /// - under 365.2425 days (one mean Gregorian year) => whole décades
+    /// - otherwise => years, to one place
+    ///
+    /// # Panics
+    ///
+    /// Panics if either date is invalid, like `signed_duration_since`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use decimal_time::DecimalTime;
+    ///
+    /// let now = DecimalTime::new(2025, 73, 0.5);
+    /// assert_eq!(DecimalTime::new(2025, 73, 0.25).humanize_since(&now), "0.25 decimal days ago");
+    /// assert_eq!(DecimalTime::new(2025, 103, 0.5).humanize_since(&now), "in 3 décades");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn humanize_since(&self, reference: &DecimalTime) -> String {
+        let days = self.signed_duration_since(reference).as_decimal_days();
+        let magnitude = crate::math::abs(days);
+        let amount = if magnitude < 0.005 {
+            return String::from("now");
+        } else if magnitude < 10.0 {
+            format!("{:.2} decimal days", magnitude)
+        } else if magnitude < 365.2425 {
+            let decades = crate::math::round(magnitude / 10.0);
+            format!("{} décade{}", decades, if decades == 1.0 { "" } else { "s" })
+        } else {
+            format!("{:.1} years", magnitude / 365.2425)
+        };
+
+        if days < 0.0 {
+            format!("{} ago", amount)
+        } else {
+            format!("in {}", amount)
+        }
+    }
+
+    /// Returns a wrapper that displays the value as `YEAR{sep}DAY.FRACTION`
+    /// according to `opts`, without allocating until it is written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use decimal_time::{DecimalTime, DisplayOptions};
+    ///
+    /// let dec = DecimalTime::new(2025, 73, 0.5);
+    /// let opts = DisplayOptions { fraction_digits: 3, separator: '/', ..Default::default() };
+    /// assert_eq!(dec.display_with(opts).to_string(), "2025/073.500");
+    /// ```
+    pub fn display_with(&self, opts: DisplayOptions) -> DecimalTimeDisplay<'_> {
+        DecimalTimeDisplay { time: self, opts }
+    }
+
+    /// Renders the short `YEAR.DAY.FRACTION` form used in URLs and keys,
+    /// e.g. `"2025.73.5"`: the day is not zero-padded and the fraction has
+    /// no leading `0`. Midnight omits the fraction (`"2025.73"`).
+    #[cfg(feature = "alloc")]
+    pub fn to_compact_string(&self) -> String {
+        self.format("%Y.%j%f")
+    }
+
+    /// Parses the form written by `to_compact_string`.
+    #[cfg(feature = "alloc")]
+    pub fn from_compact_string(s: &str) -> Result<DecimalTime, ParseError> {
+        Self::parse_from_str(s, "%Y.%j%f")
+    }
+
+    /// Like `format`, but `%'Y` groups the year's digits with `separator`
+    /// instead of `,`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let dec = decimal_time::DecimalTime::new(-262_143, 1, 0.0);
+    /// assert_eq!(dec.format_with_thousands_separator("%'Y", '_'), "-262_143");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_with_thousands_separator(&self, fmt_str: &str, separator: char) -> String {
+        self.formatted_string(fmt_str, None, false, separator)
+            .expect("lenient formatting never fails")
+    }
+
+    /// Formats the conventional date and time with chrono's `strftime`
+    /// syntax, e.g. `"%A %e %B %Y"`. Combine with `format` (or use its `%c`
+    /// token) to show the decimal fraction alongside.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let dec = decimal_time::DecimalTime::new(2025, 73, 0.5);
+    /// assert_eq!(dec.strftime_date("%d %b %Y").unwrap(), "14 Mar 2025");
+    /// assert_eq!(dec.format("%c%f"), "2025-03-14.5");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+    #[cfg(feature = "alloc")]
+    pub fn strftime_date(&self, fmt: &str) -> Result<String, FormatError> {
+        let items = chrono::format::StrftimeItems::new(fmt);
+        let mut output = String::with_capacity(fmt.len());
+        write!(output, "{}", self.to_naive_datetime().format_with_items(items))
+            .map_err(|_| FormatError::InvalidStrftime)?;
+        Ok(output)
+    }
+
+    /// Renders the instant as an RFC 3339 / ISO 8601 UTC timestamp,
+    /// e.g. `"2025-03-14T12:00:00+00:00"` for `{2025, 73, 0.5}`.
+    ///
+    /// Sub-second digits are included when present, at microsecond precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+    #[cfg(feature = "alloc")]
+    pub fn to_iso8601(&self) -> String {
+        self.to_datetime_utc().to_rfc3339()
+    }
+
+    /// Parses an RFC 3339 / ISO 8601 timestamp, converting any offset to UTC.
+    #[cfg(feature = "alloc")]
+    pub fn from_iso8601(s: &str) -> Result<DecimalTime, ParseError> {
+        let dt = chrono::DateTime::parse_from_rfc3339(s)
+            .map_err(|err| ParseError::Malformed(format!("invalid RFC 3339 timestamp {:?}: {}", s, err)))?;
+        Ok(Self::from_datetime_utc(dt.with_timezone(&chrono::Utc)))
+    }
+
+    /// Runs `write_formatted` into a new `String`.
+    #[cfg(feature = "alloc")]
+    fn formatted_string(
+        &self,
+        fmt_str: &str,
+        epoch: Option<&DecimalTime>,
+        strict: bool,
+        thousands_separator: char,
+    ) -> Result<String, FormatError> {
+        let mut output = String::with_capacity(fmt_str.len());
+        match self.write_formatted(&mut output, fmt_str, epoch, strict, thousands_separator) {
+            Ok(()) => Ok(output),
+            Err(WriteError::Format(err)) => Err(err),
+            Err(WriteError::Fmt(_)) => unreachable!("writing to a String cannot fail"),
+        }
+    }
+
+    /// Single left-to-right pass over `fmt_str`, writing each literal
+    /// character or token value to `output`.
+    ///
+    /// `%e` is only a token when an `epoch` is given. With `strict` unset,
+    /// unknown specifiers and a trailing `%` are copied through.
+    /// `%'Y` groups the year with `thousands_separator`.
+    fn write_formatted<W: Write + ?Sized>(
+        &self,
+        output: &mut W,
+        fmt_str: &str,
+        epoch: Option<&DecimalTime>,
+        strict: bool,
+        thousands_separator: char,
+    ) -> Result<(), WriteError> {
+        for (position, item) in Items::new(fmt_str) {
+            self.write_item(output, position, item, epoch, strict, thousands_separator)?;
+        }
+        Ok(())
+    }
+
+    /// Writes one token of a format string (found at byte `position`) to
+    /// `output`, see `write_formatted`.
+    pub(crate) fn write_item<W: Write + ?Sized>(
+        &self,
+        output: &mut W,
+        position: usize,
+        item: Item,
+        epoch: Option<&DecimalTime>,
+        strict: bool,
+        thousands_separator: char,
+    ) -> Result<(), WriteError> {
+        match item {
+            Item::Literal(c) => output.write_char(c)?,
+            Item::Year { width } => write!(output, "{:0w$}", self.year, w = width)?,
+            Item::GroupedYear => write_grouped(output, self.year, thousands_separator)?,
+            Item::Day { width } => write!(output, "{:0w$}", self.day_of_year, w = width)?,
+            Item::Fraction { precision: None } => {
+                // `+ 0.0` writes `-0.0` as midnight, not `-0`
+                let mut trimmed = SkipLeadingZeros { inner: output, started: false };
+                write!(trimmed, "{}", self.decimal_day + 0.0)?;
+            }
+            Item::Fraction { precision: Some(precision) } => {
+                // Round to nearest, but never up to a full day; past `f64`
+                // precision the stored fraction is written as is
+                let fraction = if precision > crate::math::MAX_FRACTION_DIGITS as usize {
+                    self.decimal_day
+                } else {
+                    let scale = crate::math::pow10(precision as u32);
+                    crate::math::round(self.decimal_day * scale).min(scale - 1.0) / scale
+                } + 0.0;
+                let mut trimmed = SkipLeadingZeros { inner: output, started: false };
+                write!(trimmed, "{:.*}", precision, fraction)?;
+            }
+            Item::WallClock { seconds: true } => {
+                let (hour, minute, second, _) = self.wall_clock();
+                write!(output, "{:02}:{:02}:{:02}", hour, minute, second)?;
+            }
+            Item::WallClock { seconds: false } => {
+                let (hour, minute, _, _) = self.wall_clock();
+                write!(output, "{:02}:{:02}", hour, minute)?;
+            }
+            Item::Percent { precision: None } if self.decimal_day.is_finite() => {
+                // Shift the shortest fraction's point instead of multiplying,
+                // which would write 0.07 as `7.000000000000001`
+                let mut percent = ShiftPointTwo {
+                    inner: SkipLeadingZeros { inner: output, started: false },
+                    fraction_digits: None,
+                };
+                write!(percent, "{}", self.decimal_day + 0.0)?;
+                percent.finish()?;
+                output.write_char('%')?;
+            }
+            Item::Percent { precision: None } => write!(output, "{}%", self.decimal_day * 100.0)?,
+            Item::Percent { precision: Some(precision) } => {
+                write!(output, "{:.*}%", precision, self.decimal_day * 100.0)?
+            }
+            Item::Date => {
+                let date = self.to_naive_datetime().date();
+                // Same year padding as chrono's `%Y`
+                if (0..=9999).contains(&date.year()) {
+                    write!(output, "{:04}", date.year())?;
+                } else {
+                    write!(output, "{:+}", date.year())?;
+                }
+                write!(output, "-{:02}-{:02}", date.month(), date.day())?;
+            }
+            Item::Weekday => write!(output, "{}", self.weekday())?,
+            Item::DaysSince => match epoch {
+                Some(epoch) => write!(output, "{}", self.to_days_since(epoch))?,
+                None if strict => {
+                    return Err(WriteError::Format(FormatError::UnknownSpecifier { specifier: 'e', position }))
+                }
+                None => output.write_str("%e")?,
+            },
+            Item::Unknown(specifier) if strict => {
+                return Err(WriteError::Format(FormatError::UnknownSpecifier { specifier, position }))
+            }
+            Item::Unknown(specifier) => {
+                output.write_char('%')?;
+                output.write_char(specifier)?;
+            }
+            Item::TrailingPercent if strict => return Err(WriteError::Format(FormatError::TrailingPercent)),
+            Item::TrailingPercent => output.write_char('%')?,
+        }
+        Ok(())
+    }
+}
+
+/// One token of a format string, as read by `Items`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Item {
+    /// A character matched as-is (including the `%` of `%%`).
+    Literal(char),
+    /// `%Y`, or `%0NY` zero-padded to `N` digits.
+    Year { width: usize },
+    /// `%'Y`, grouped with thousands separators.
+    GroupedYear,
+    /// `%d` (width 3), `%j` (width 0), or `%0Nd` / `%0Nj`.
+    Day { width: usize },
+    /// `%f`, or `%.Nf` to `N` places.
+    Fraction { precision: Option<usize> },
+    /// `%r` / `%T` (`HH:MM:SS`), or `%R` (`HH:MM`).
+    WallClock { seconds: bool },
+    /// `%p`, or `%.Np` to `N` places.
+    Percent { precision: Option<usize> },
+    /// `%c`
+    Date,
+    /// `%w`
+    Weekday,
+    /// `%e`, which needs an epoch.
+    DaysSince,
+    /// `%` followed by a character that does not start a known specifier.
+    Unknown(char),
+    /// A lone `%` at the end of the format string.
+    TrailingPercent,
+}
+
+impl Item {
+    /// Whether this item's output can start with an ASCII digit, so that a
+    /// number written just before it has no visible end.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn starts_with_digit(&self) -> bool {
+        match self {
+            Item::Literal(c) => c.is_ascii_digit(),
+            Item::Year { .. }
+            | Item::GroupedYear
+            | Item::Day { .. }
+            | Item::WallClock { .. }
+            | Item::Percent { .. }
+            | Item::Date
+            | Item::DaysSince => true,
+            Item::Fraction { .. } | Item::Weekday | Item::Unknown(_) | Item::TrailingPercent => false,
+        }
+    }
+}
+
+/// Splits a format string into `(byte position, Item)` pairs, left to
+/// right, so substituted values are never re-read as tokens.
+pub(crate) struct Items<'a> {
+    fmt: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Items<'a> {
+    pub(crate) fn new(fmt: &'a str) -> Self {
+        Items { fmt, rest: fmt }
+    }
+}
+
+impl Iterator for Items<'_> {
+    type Item = (usize, Item);
+
+    fn next(&mut self) -> Option<(usize, Item)> {
+        let position = self.fmt.len() - self.rest.len();
+        let mut chars = self.rest.chars();
+        let c = chars.next()?;
+        let spec = chars.as_str();
+        if c != '%' {
+            self.rest = spec;
+            return Some((position, Item::Literal(c)));
+        }
+
+        let (item, len) = match spec.chars().next() {
+            Some('Y') => (Item::Year { width: 0 }, 1),
+            Some('\'') if spec[1..].starts_with('Y') => (Item::GroupedYear, 2),
+            Some('d') => (Item::Day { width: 3 }, 1),
+            Some('j') => (Item::Day { width: 0 }, 1),
+            Some('f') => (Item::Fraction { precision: None }, 1),
+            Some('r' | 'T') => (Item::WallClock { seconds: true }, 1),
+            Some('R') => (Item::WallClock { seconds: false }, 1),
+            Some('p') => (Item::Percent { precision: None }, 1),
+            Some('c') => (Item::Date, 1),
+            Some('w') => (Item::Weekday, 1),
+            Some('e') => (Item::DaysSince, 1),
+            Some('%') => (Item::Literal('%'), 1),
+            Some('.') => match numbered_spec(&spec[1..], &['p', 'f']) {
+                Some((precision, 'p', len)) => (Item::Percent { precision: Some(precision) }, 1 + len),
+                Some((precision, _, len)) => (Item::Fraction { precision: Some(precision) }, 1 + len),
+                None => (Item::Unknown('.'), 1),
+            },
+            Some('0') => match numbered_spec(&spec[1..], &['Y', 'd', 'j']) {
+                Some((width, 'Y', len)) => (Item::Year { width }, 1 + len),
+                Some((width, _, len)) => (Item::Day { width }, 1 + len),
+                None => (Item::Unknown('0'), 1),
+            },
+            Some(specifier) => (Item::Unknown(specifier), specifier.len_utf8()),
+            None => (Item::TrailingPercent, 0),
+        };
+        self.rest = &spec[len..];
+        Some((position, item))
+    }
+}
+
+/// Layout options for `DecimalTime::display_with`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// Digits after the decimal point, rounded to nearest (carrying into
+    /// the next day when rounding up from the end of a day). `0` omits the
+    /// fraction entirely and shows the current day, unrounded. Values above
+    /// 17, the most an `f64` fraction holds, are treated as 17.
+    pub fraction_digits: u32,
+    /// Zero-pad the day of year to three digits.
+    pub pad_day: bool,
+    /// Written between the year and the day.
+    pub separator: char,
+}
+
+/// `2025-073.500000`: six fraction digits, padded day, `-` separator.
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        DisplayOptions {
+            fraction_digits: 6,
+            pad_day: true,
+            separator: '-',
+        }
+    }
+}
+
+/// A `DecimalTime` paired with `DisplayOptions`, returned by `display_with`.
+#[derive(Debug, Clone, Copy)]
+pub struct DecimalTimeDisplay<'a> {
+    time: &'a DecimalTime,
+    opts: DisplayOptions,
+}
+
+impl fmt::Display for DecimalTimeDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.opts.fraction_digits.min(crate::math::MAX_FRACTION_DIGITS);
+        // Invalid dates cannot carry, so they are shown unrounded
+        let t = match digits {
+            0 => *self.time,
+            _ => self
+                .time
+                .checked_round(digits, RoundingMode::Nearest)
+                .unwrap_or(*self.time),
+        };
+
+        write!(f, "{}{}", t.year, self.opts.separator)?;
+        if self.opts.pad_day {
+            write!(f, "{:03}", t.day_of_year)?;
+        } else {
+            write!(f, "{}", t.day_of_year)?;
+        }
+        if digits > 0 {
+            let mut trimmed = SkipLeadingZeros { inner: f, started: false };
+            write!(trimmed, "{:.*}", digits as usize, t.decimal_day + 0.0)?;
+        }
+        Ok(())
+    }
+}
+
+/// A `DecimalTime` paired with a format string, returned by `format_lazy`.
+#[derive(Debug, Clone, Copy)]
+pub struct DecimalTimeFormat<'a> {
+    time: &'a DecimalTime,
+    fmt_str: &'a str,
+}
+
+impl fmt::Display for DecimalTimeFormat<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.time
+            .write_formatted(f, self.fmt_str, None, false, ',')
+            .map_err(|_| fmt::Error)
+    }
+}
+
+/// The canonical form `YEAR-DDD.F`, e.g. `2025-100.5`: the zero-padded
+/// day and the full fraction, as `format("%Y-%d%f")` writes them.
+///
+/// The fraction uses the shortest form that reads back as the same `f64`,
+/// so `FromStr` returns exactly the displayed value. Midnight omits the
+/// fraction (`2025-100`). Use `display_with` for a fixed number of places.
+impl fmt::Display for DecimalTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_formatted(f, "%Y-%d%f", None, false, ',')
+            .map_err(|_| fmt::Error)
+    }
+}
+
+/// Parses the canonical form written by `Display`, e.g. `2025-100.5`.
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for DecimalTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_from_str(s, "%Y-%d%f")
+    }
+}
+
+/// Why `write_formatted` stopped: a bad format string or a failing writer.
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+pub(crate) enum WriteError {
+    Format(FormatError),
+    Fmt(fmt::Error),
+}
+
+impl From<fmt::Error> for WriteError {
+    fn from(err: fmt::Error) -> Self {
+        WriteError::Fmt(err)
+    }
+}
+
+/// Forwards to `inner`, dropping any `0`s before the first other character
+/// (turning `0.5` into `.5`, and `0` into nothing).
+struct SkipLeadingZeros<'a, W: Write + ?Sized> {
+    inner: &'a mut W,
+    started: bool,
+}
+
+impl<W: Write + ?Sized> Write for SkipLeadingZeros<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let s = if self.started { s } else { s.trim_start_matches('0') };
+        if s.is_empty() {
+            return Ok(());
+        }
+        self.started = true;
+        self.inner.write_str(s)
+    }
+}
+
+/// Forwards a decimal number to `inner` with its point moved two places
+/// right, so `0.125` becomes `12.5` and `0.5` becomes `50` (after `finish`).
+struct ShiftPointTwo<'a, W: Write + ?Sized> {
+    inner: SkipLeadingZeros<'a, W>,
+    /// Digits seen after the point, `None` before it.
+    fraction_digits: Option<usize>,
+}
+
+impl<W: Write + ?Sized> ShiftPointTwo<'_, W> {
+    /// Pads a fraction shorter than two digits, and writes `0` if every
+    /// digit was a leading zero.
+    fn finish(&mut self) -> fmt::Result {
+        for _ in self.fraction_digits.unwrap_or(0)..2 {
+            self.inner.write_char('0')?;
+        }
+        if !self.inner.started {
+            self.inner.inner.write_char('0')?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write + ?Sized> Write for ShiftPointTwo<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match (c, self.fraction_digits) {
+                ('-', None) => self.inner.inner.write_char('-')?,
+                ('.', None) => self.fraction_digits = Some(0),
+                (_, None) => self.inner.write_char(c)?,
+                (_, Some(n)) => {
+                    if n == 2 {
+                        if !self.inner.started {
+                            self.inner.inner.write_char('0')?;
+                            self.inner.started = true;
+                        }
+                        self.inner.write_char('.')?;
+                    }
+                    self.inner.write_char(c)?;
+                    self.fraction_digits = Some(n + 1);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes `value` with `separator` between each group of three digits.
+fn write_grouped<W: Write + ?Sized>(output: &mut W, value: i32, separator: char) -> fmt::Result {
+    if value < 0 {
+        output.write_char('-')?;
+    }
+    let mut digits = [0u8; 10];
+    let mut len = 0;
+    let mut rest = value.unsigned_abs();
+    loop {
+        digits[len] = b'0' + (rest % 10) as u8;
+        len += 1;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    for i in (0..len).rev() {
+        output.write_char(digits[i] as char)?;
+        if i > 0 && i % 3 == 0 {
+            output.write_char(separator)?;
+        }
+    }
+    Ok(())
+}
+
+/// Largest width or precision `numbered_spec` accepts; larger ones are
+/// unknown specifiers rather than a panic inside `core::fmt`.
+const MAX_SPEC_NUMBER: usize = 99;
+
+/// Matches `N` followed by one of `tokens` at the start of `rest` (as in
+/// `%.3f` or `%03j`), returning `N`, the token and the number of bytes
+/// (all ASCII) they span. `N` must not exceed `MAX_SPEC_NUMBER`.
+fn numbered_spec(rest: &str, tokens: &[char]) -> Option<(usize, char, usize)> {
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+    let token = rest[digits..].chars().next().filter(|c| tokens.contains(c))?;
+    if digits == 0 {
+        return None;
+    }
+    let n = rest[..digits].parse().ok().filter(|&n| n <= MAX_SPEC_NUMBER)?;
+    Some((n, token, digits + 1))
+}
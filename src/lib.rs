@@ -1,15 +1,169 @@
 //! # Decimal Time
 //!
 //! A Rust library that implements a custom date/time format called "Decimal Time."
+//!
+//! ## Features
+//!
+//! - `std` (default) - enables `alloc` plus `DecimalTime::now()` and `SystemClock`.
+//! - `alloc` - enables the `String`-returning formatting and the text parsers.
+//! - `time` - conversions to and from the `time` crate's `OffsetDateTime` and
+//!   `PrimitiveDateTime`.
+//! - `rand` - `DecimalTime::sample_uniform` for reproducible random timestamps.
+//! - `rayon` - parallel batch conversions such as
+//!   `DecimalTime::par_from_datetimes_utc` (implies `std`).
+//! - `republican` - French Republican calendar month and day names.
+//! - `serde` - `Serialize`/`Deserialize` for `DecimalTime`, `DecimalDuration`
+//!   and `DecimalTimeRange`, with `DecimalTime` representations selectable
+//!   through the `decimal_time::serde` helper modules.
+//! - `schemars` - `JsonSchema` for the same types, plus `json_schema`
+//!   functions for the string representations (implies `serde` and `alloc`).
+//!
+//! The crate is `no_std` when `std` is disabled. The core struct, conversions
+//! and arithmetic stay available in a bare build; `chrono` must then also be
+//! built without its default features:
+//!
+//! ```toml
+//! [dependencies]
+//! decimal_time = { version = "0.1", default-features = false, features = ["alloc"] }
+//! chrono = { version = "0.4", default-features = false }
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, DateTime, Utc};
+
+mod clock;
+mod components;
+mod date;
+mod duration;
+mod error;
+mod exact;
+mod format;
+mod math;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "alloc")]
+mod parse;
+#[cfg(feature = "rand")]
+mod random;
+mod range;
+#[cfg(feature = "republican")]
+mod republican;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "alloc")]
+mod spec;
+#[cfg(feature = "time")]
+mod time_compat;
+
+pub use clock::Clock;
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+pub use components::DecimalComponents;
+pub use date::DecimalDate;
+pub use duration::DecimalDuration;
+pub use error::{DecimalTimeError, FormatError};
+pub use exact::DecimalTimeExact;
+pub use format::{DecimalTimeDisplay, DecimalTimeFormat, DisplayOptions};
+#[cfg(feature = "alloc")]
+pub use error::ParseError;
+pub use range::DecimalTimeRange;
+#[cfg(feature = "alloc")]
+pub use spec::FormatSpec;
+
+/// One decimal minute (1/1000 of a day) in microseconds.
+const MICROS_PER_DECIMAL_MINUTE: i64 = 86_400_000;
+
+/// One day in microseconds.
+pub(crate) const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+/// Place values of the year and day fields in `to_packed`.
+const PACKED_YEAR: i128 = 100_000_000_000_000;
+const PACKED_DAY: i128 = 100_000_000_000;
+
+/// Flips the sign of the year in `to_bytes` so the bytes sort chronologically.
+const SIGN_BIT: u32 = 0x8000_0000;
+
+/// Days in one 400-year Gregorian cycle.
+const DAYS_PER_400_YEARS: i64 = 146_097;
+
+/// `num_days_from_ce` of 1970-01-01.
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
 
-use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike, DateTime, Utc};
+/// One day in seconds.
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// One day in nanoseconds.
+pub(crate) const NANOS_PER_DAY: u64 = 86_400_000_000_000;
+
+/// Returns `true` if `year` is a Gregorian leap year.
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days in `year` (365 or 366).
+pub fn days_in_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Checks `day_of_year` against the real length of `year`
+/// and `decimal_day` against [0,1).
+fn validate(year: i32, day_of_year: u32, decimal_day: f64) -> Result<(), DecimalTimeError> {
+    if !(DecimalTime::MIN_YEAR..=DecimalTime::MAX_YEAR).contains(&year) {
+        return Err(DecimalTimeError::YearOutOfRange(year));
+    }
+    if !(0.0..1.0).contains(&decimal_day) {
+        return Err(DecimalTimeError::DecimalDayOutOfRange(decimal_day));
+    }
+    if !(1..=days_in_year(year)).contains(&day_of_year) {
+        return Err(DecimalTimeError::DayOfYearOutOfRange { year, day_of_year });
+    }
+    Ok(())
+}
+
+/// Converts a time of day into a fraction of the day, at microsecond precision.
+fn fraction_of_day(t: NaiveTime) -> f64 {
+    // total seconds in the day
+    let sec_in_day = t.num_seconds_from_midnight();
+    let nano = t.nanosecond();
+
+    // Convert to microseconds
+    let total_microseconds = (sec_in_day as u64) * 1_000_000 + (nano / 1_000) as u64;
+    // 86,400 seconds in a day => 86,400_000_000 microseconds
+    total_microseconds as f64 / 86_400_000_000.0
+}
+
+/// How `round_to_places` / `checked_round` treat the discarded digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round half away from zero.
+    Nearest,
+    /// Round towards the next day.
+    Up,
+    /// Truncate towards midnight.
+    Down,
+}
 
 /// A struct representing a date/time in “Decimal Time”:
 ///
 /// - `year`: full year (e.g., 2025)
 /// - `day_of_year`: the day of year (1-based, in [1..=365 or 366])
 /// - `decimal_day`: fraction of the day (0.0 <= decimal_day < 1.0)
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// Values are ordered chronologically by `year`, then `day_of_year`, then
+/// `decimal_day` (compared with `f64::total_cmp`, treating `-0.0` as
+/// `0.0`), so `min`/`max`/`clamp` and sorting come from `Ord`.
+#[derive(Debug, Clone, Copy)]
 pub struct DecimalTime {
     pub year: i32,
     pub day_of_year: u32,
@@ -22,22 +176,92 @@ impl DecimalTime {
     ///
     /// # Panics
     ///
-    /// Panics if `decimal_day` is out of [0,1)
-    /// or if `day_of_year` is out of 1..=366.
+    /// Panics if `try_new` would fail: `decimal_day` out of [0,1),
+    /// `day_of_year` out of range for `year`, or `year` outside
+    /// `MIN_YEAR..=MAX_YEAR`.
     pub fn new(year: i32, day_of_year: u32, decimal_day: f64) -> Self {
-        if !(0.0..1.0).contains(&decimal_day) {
-            panic!(
-                "`decimal_day` must be in [0,1). Received: {}",
-                decimal_day
-            );
-        }
-        if !(1..=366).contains(&day_of_year) {
-            panic!(
-                "`day_of_year` must be in [1..=366]. Received: {}",
-                day_of_year
-            );
-        }
+        Self::try_new(year, day_of_year, decimal_day).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a new `DecimalTime`, reporting which field is out of range
+    /// instead of panicking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use decimal_time::{DecimalTime, DecimalTimeError};
+    ///
+    /// assert!(DecimalTime::try_new(2025, 73, 0.5).is_ok());
+    /// assert_eq!(
+    ///     DecimalTime::try_new(2025, 366, 0.5),
+    ///     Err(DecimalTimeError::DayOfYearOutOfRange { year: 2025, day_of_year: 366 })
+    /// );
+    /// ```
+    pub fn try_new(year: i32, day_of_year: u32, decimal_day: f64) -> Result<Self, DecimalTimeError> {
+        validate(year, day_of_year, decimal_day)?;
+        Ok(DecimalTime {
+            year,
+            day_of_year,
+            decimal_day,
+        })
+    }
+
+    /// Returns a copy with `decimal_day` replaced, after validating it.
+    pub fn with_decimal_day(self, decimal_day: f64) -> Result<Self, DecimalTimeError> {
+        validate(self.year, self.day_of_year, decimal_day)?;
+        Ok(DecimalTime { decimal_day, ..self })
+    }
+
+    /// Returns a copy with `decimal_day` set from a time-of-day offset,
+    /// e.g. `Duration::hours(6)` for 0.25.
+    ///
+    /// Fails if `d` is negative or a full day or longer.
+    pub fn with_time_of_day(self, d: chrono::Duration) -> Result<Self, DecimalTimeError> {
+        let decimal_day = match d.num_microseconds() {
+            Some(micros) => micros as f64 / MICROS_PER_DAY as f64,
+            None => d.num_seconds() as f64 / SECONDS_PER_DAY,
+        };
+        self.with_decimal_day(decimal_day)
+    }
+
+    /// Returns a copy with `day_of_year` replaced, after validating it
+    /// against the length of the current year.
+    pub fn with_day_of_year(self, day_of_year: u32) -> Result<Self, DecimalTimeError> {
+        validate(self.year, day_of_year, self.decimal_day)?;
+        Ok(DecimalTime { day_of_year, ..self })
+    }
+
+    /// Returns a copy with `year` replaced.
+    ///
+    /// Fails if `day_of_year` is 366 and the new year is not a leap year.
+    pub fn with_year(self, year: i32) -> Result<Self, DecimalTimeError> {
+        validate(year, self.day_of_year, self.decimal_day)?;
+        Ok(DecimalTime { year, ..self })
+    }
+
+    /// The earliest supported year, that of chrono's `NaiveDate::MIN`.
+    pub const MIN_YEAR: i32 = -262_143;
+
+    /// The latest supported year, that of chrono's `NaiveDate::MAX`.
+    pub const MAX_YEAR: i32 = 262_142;
+
+    /// The earliest representable `DecimalTime`: midnight on chrono's
+    /// `NaiveDate::MIN` (January 1st, year -262143).
+    pub const MIN: DecimalTime = DecimalTime::new_unchecked(Self::MIN_YEAR, 1, 0.0);
 
+    /// The latest representable `DecimalTime`: the last microsecond of
+    /// chrono's `NaiveDate::MAX` (December 31st, year 262142).
+    pub const MAX: DecimalTime = DecimalTime::new_unchecked(
+        Self::MAX_YEAR,
+        365,
+        (MICROS_PER_DAY - 1) as f64 / MICROS_PER_DAY as f64,
+    );
+
+    /// Creates a new `DecimalTime` without validating any field.
+    ///
+    /// Usable in const contexts; the caller is responsible for passing
+    /// values that `new` would accept.
+    pub const fn new_unchecked(year: i32, day_of_year: u32, decimal_day: f64) -> Self {
         DecimalTime {
             year,
             day_of_year,
@@ -45,21 +269,65 @@ impl DecimalTime {
         }
     }
 
+    /// The earliest representable `DecimalTime`, same as `DecimalTime::MIN`.
+    pub fn min_value() -> DecimalTime {
+        Self::MIN
+    }
+
+    /// The latest representable `DecimalTime`, same as `DecimalTime::MAX`.
+    pub fn max_value() -> DecimalTime {
+        Self::MAX
+    }
+
     /// Converts a `chrono::NaiveDateTime` to a `DecimalTime`.
     pub fn from_naive_datetime(dt: NaiveDateTime) -> Self {
         let year = dt.year();
         let day_of_year = dt.ordinal();
 
-        // total seconds in the day
-        let sec_in_day = dt.num_seconds_from_midnight();
-        let nano = dt.nanosecond();
+        Self::new(year, day_of_year, fraction_of_day(dt.time()))
+    }
+
+    /// Converts a `chrono::NaiveTime` on the given day into a `DecimalTime`,
+    /// using the same microsecond arithmetic as `from_naive_datetime`.
+    ///
+    /// Fails if `day_of_year` is out of range for `year`, or if `t` is a
+    /// leap second that falls past the end of the day.
+    pub fn from_naive_time(year: i32, day_of_year: u32, t: NaiveTime) -> Result<DecimalTime, DecimalTimeError> {
+        let decimal_day = fraction_of_day(t);
+        validate(year, day_of_year, decimal_day)?;
+        Ok(DecimalTime::new_unchecked(year, day_of_year, decimal_day))
+    }
+
+    /// Creates a `DecimalTime` from a calendar date and a separately
+    /// computed fraction of that day.
+    ///
+    /// Fails if `decimal_day` is out of [0,1).
+    pub fn from_date_and_fraction(date: NaiveDate, decimal_day: f64) -> Result<DecimalTime, DecimalTimeError> {
+        validate(date.year(), date.ordinal(), decimal_day)?;
+        Ok(DecimalTime::new_unchecked(date.year(), date.ordinal(), decimal_day))
+    }
+
+    /// Midnight (fraction 0.0) on the given day.
+    ///
+    /// Fails if `day_of_year` is out of range for `year`.
+    pub fn midnight(year: i32, day_of_year: u32) -> Result<DecimalTime, DecimalTimeError> {
+        validate(year, day_of_year, 0.0)?;
+        Ok(DecimalTime::new_unchecked(year, day_of_year, 0.0))
+    }
 
-        // Convert to microseconds
-        let total_microseconds = (sec_in_day as u64) * 1_000_000 + (nano / 1_000) as u64;
-        // 86,400 seconds in a day => 86,400_000_000 microseconds
-        let fraction_of_day = total_microseconds as f64 / 86_400_000_000.0;
+    /// Noon (fraction 0.5) on the given day.
+    ///
+    /// Fails if `day_of_year` is out of range for `year`.
+    pub fn noon(year: i32, day_of_year: u32) -> Result<DecimalTime, DecimalTimeError> {
+        validate(year, day_of_year, 0.5)?;
+        Ok(DecimalTime::new_unchecked(year, day_of_year, 0.5))
+    }
 
-        Self::new(year, day_of_year, fraction_of_day)
+    /// Midnight on the first day of `year`.
+    ///
+    /// Fails if `year` is outside `MIN_YEAR..=MAX_YEAR`.
+    pub fn start_of_year(year: i32) -> Result<DecimalTime, DecimalTimeError> {
+        DecimalTime::midnight(year, 1)
     }
 
     /// Converts a UTC `chrono::DateTime<Utc>` into a `DecimalTime`.
@@ -67,6 +335,69 @@ impl DecimalTime {
         Self::from_naive_datetime(dt.naive_utc())
     }
 
+    /// Converts milliseconds since the Unix epoch (UTC) into a `DecimalTime`.
+    ///
+    /// Returns `None` if the timestamp is out of chrono's range.
+    pub fn from_unix_timestamp_millis(millis: i64) -> Option<DecimalTime> {
+        DateTime::<Utc>::from_timestamp_millis(millis).map(Self::from_datetime_utc)
+    }
+
+    /// Returns the instant as a `google.protobuf.Timestamp`-style
+    /// `(seconds, nanos)` pair since the Unix epoch, with `nanos` in
+    /// `0..1_000_000_000` (microsecond precision).
+    ///
+    /// Returns `None` if the date is invalid.
+    pub fn to_prost_timestamp(&self) -> Option<(i64, i32)> {
+        let dt = self.to_naive_datetime_opt()?.and_utc();
+        Some((dt.timestamp(), dt.timestamp_subsec_nanos() as i32))
+    }
+
+    /// Converts a `google.protobuf.Timestamp`-style `(seconds, nanos)` pair
+    /// into a `DecimalTime`, truncating `nanos` to microseconds.
+    ///
+    /// Returns `None` if `nanos` is outside `0..1_000_000_000` or the
+    /// timestamp is out of chrono's range.
+    pub fn from_prost_timestamp(seconds: i64, nanos: i32) -> Option<DecimalTime> {
+        let nanos = u32::try_from(nanos).ok().filter(|n| *n < 1_000_000_000)?;
+        DateTime::<Utc>::from_timestamp(seconds, nanos).map(Self::from_datetime_utc)
+    }
+
+    /// Converts a `std::time::SystemTime` into a `DecimalTime` (UTC),
+    /// at millisecond precision.
+    ///
+    /// Returns `None` for times before the Unix epoch or out of range.
+    #[cfg(feature = "std")]
+    pub fn from_system_time(t: std::time::SystemTime) -> Option<DecimalTime> {
+        let since_epoch = t.duration_since(std::time::UNIX_EPOCH).ok()?;
+        Self::from_unix_timestamp_millis(i64::try_from(since_epoch.as_millis()).ok()?)
+    }
+
+    /// Converts `DecimalTime` into a `std::time::SystemTime`, treating it as UTC.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> std::time::SystemTime {
+        let micros = self.to_datetime_utc().timestamp_micros();
+        let offset = std::time::Duration::from_micros(micros.unsigned_abs());
+        if micros >= 0 {
+            std::time::UNIX_EPOCH + offset
+        } else {
+            std::time::UNIX_EPOCH - offset
+        }
+    }
+
+    /// Returns `true` if every field is in range: `decimal_day` in [0,1),
+    /// `day_of_year` within the year's real length, and `year` within
+    /// chrono's representable range.
+    ///
+    /// A valid value converts without panicking; for a fallible conversion
+    /// use `to_naive_datetime_opt`.
+    pub fn is_valid(&self) -> bool {
+        validate(self.year, self.day_of_year, self.decimal_day).is_ok()
+    }
+
     /// Converts `DecimalTime` into a `chrono::NaiveDateTime`.
     ///
     /// # Panics
@@ -82,7 +413,16 @@ impl DecimalTime {
                 )
             });
 
-        let total_microseconds = (self.decimal_day * 86_400_000_000.0).round() as u64;
+        let total_microseconds = math::round(self.decimal_day * 86_400_000_000.0) as u64;
+        if total_microseconds >= MICROS_PER_DAY as u64 {
+            // `decimal_day` was so close to 1.0 that it rounded up to a full
+            // day: that is the next day's midnight, not 24:00:00 today.
+            return base_date
+                .succ_opt()
+                .expect("next day is out of range")
+                .and_hms_micro_opt(0, 0, 0, 0)
+                .unwrap(); // safe as it’s midnight
+        }
         let seconds = total_microseconds / 1_000_000;
         let micros = total_microseconds % 1_000_000;
 
@@ -95,6 +435,49 @@ impl DecimalTime {
             .unwrap()
     }
 
+    /// Converts `DecimalTime` into a `chrono::NaiveDateTime`,
+    /// returning `None` instead of panicking if the date is invalid.
+    pub fn to_naive_datetime_opt(&self) -> Option<NaiveDateTime> {
+        let base_date = NaiveDate::from_yo_opt(self.year, self.day_of_year)?;
+        if !(0.0..1.0).contains(&self.decimal_day) {
+            return None;
+        }
+
+        let total_microseconds = math::round(self.decimal_day * 86_400_000_000.0) as i64;
+        base_date
+            .and_hms_micro_opt(0, 0, 0, 0)?
+            .checked_add_signed(chrono::Duration::microseconds(total_microseconds))
+    }
+
+    /// Converts `DecimalTime` into a `chrono::NaiveDateTime`, clamping
+    /// invalid or out-of-range fields instead of panicking:
+    ///
+    /// - `year` below/above chrono's range => `NaiveDateTime::MIN`/`MAX`
+    /// - `day_of_year` is clamped to the year's real length
+    ///   (so day 366 of a non-leap year becomes Dec 31)
+    /// - `decimal_day` is clamped into [0,1) (`NaN` becomes midnight)
+    pub fn to_naive_datetime_saturating(&self) -> NaiveDateTime {
+        if self.year < NaiveDate::MIN.year() {
+            return NaiveDateTime::MIN;
+        }
+        if self.year > NaiveDate::MAX.year() {
+            return NaiveDateTime::MAX;
+        }
+
+        let clamped = DecimalTime {
+            year: self.year,
+            day_of_year: self.day_of_year.clamp(1, days_in_year(self.year)),
+            decimal_day: if self.decimal_day.is_nan() {
+                0.0
+            } else {
+                self.decimal_day.clamp(0.0, Self::max_value().decimal_day)
+            },
+        };
+        clamped
+            .to_naive_datetime_opt()
+            .expect("clamped fields are always representable")
+    }
+
     /// Converts `DecimalTime` into a UTC `chrono::DateTime<Utc>`.
     pub fn to_datetime_utc(&self) -> DateTime<Utc> {
         let ndt = self.to_naive_datetime();
@@ -102,35 +485,1184 @@ impl DecimalTime {
         DateTime::<Utc>::from_naive_utc_and_offset(ndt, Utc)
     }
 
-    /// Format `DecimalTime` with simple placeholders:
-    /// - `%Y` => year
-    /// - `%d` => day_of_year (3-digit zero-padded)
-    /// - `%f` => fraction of day
+    /// Returns `true` if `self`, taken as UTC, is strictly earlier than `dt`.
     ///
-    /// # Example
-    /// 
-    /// ```
-    /// let dec = decimal_time::DecimalTime::new(2025, 100, 0.5);
-    /// let s = dec.format("Year=%Y Day=%d Fraction=%f");
-    /// // => "Year=2025 Day=100 Fraction=0.5"
-    /// ```
-    pub fn format(&self, fmt_str: &str) -> String {
-        let mut output = fmt_str.to_string();
+    /// `self` is converted once via `to_naive_datetime_opt`; an invalid
+    /// date is neither before nor after anything and returns `false`.
+    pub fn is_before(&self, dt: DateTime<Utc>) -> bool {
+        self.to_naive_datetime_opt()
+            .is_some_and(|ndt| ndt.and_utc() < dt)
+    }
+
+    /// Returns `true` if `self`, taken as UTC, is strictly later than `dt`.
+    ///
+    /// Returns `false` for an invalid date, like `is_before`.
+    pub fn is_after(&self, dt: DateTime<Utc>) -> bool {
+        self.to_naive_datetime_opt()
+            .is_some_and(|ndt| ndt.and_utc() > dt)
+    }
+
+    /// Returns the earlier of `a` and `b` (`a` if they are equal).
+    pub fn earliest(a: DecimalTime, b: DecimalTime) -> DecimalTime {
+        core::cmp::min(a, b)
+    }
+
+    /// Returns the later of `a` and `b` (`b` if they are equal).
+    pub fn latest(a: DecimalTime, b: DecimalTime) -> DecimalTime {
+        core::cmp::max(a, b)
+    }
+
+    /// Returns the microsecond of the day, rounded the same way as `to_naive_datetime`.
+    pub fn microseconds_into_day(&self) -> u64 {
+        math::round(self.decimal_day * MICROS_PER_DAY as f64) as u64
+    }
+
+    /// Creates a `DecimalTime` whose `microseconds_into_day()` is exactly `micros`.
+    ///
+    /// Returns `None` if `micros` is a full day or more, or the date is invalid.
+    pub fn from_microseconds_into_day(year: i32, day_of_year: u32, micros: u64) -> Option<DecimalTime> {
+        if micros >= MICROS_PER_DAY as u64 {
+            return None;
+        }
+        let decimal_day = micros as f64 / MICROS_PER_DAY as f64;
+        validate(year, day_of_year, decimal_day).ok()?;
+
+        Some(DecimalTime {
+            year,
+            day_of_year,
+            decimal_day,
+        })
+    }
+
+    /// Snaps `decimal_day` to the nearest whole microsecond of the day,
+    /// carrying into the next day if it rounds up to midnight.
+    ///
+    /// The result converts to and from `NaiveDateTime` without any change,
+    /// which makes it safe to store and compare exactly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+    pub fn nearest_representable(self) -> DecimalTime {
+        Self::from_naive_datetime(self.to_naive_datetime())
+    }
+
+    /// Returns the exact nanosecond of the day (0 to 86,399,999,999,999),
+    /// rounding `decimal_day` to the nearest nanosecond.
+    pub fn nanos_of_day(&self) -> u64 {
+        let nanos = math::round(self.decimal_day * NANOS_PER_DAY as f64) as u64;
+        nanos.min(NANOS_PER_DAY - 1)
+    }
+
+    /// Creates a `DecimalTime` from a nanosecond of the day.
+    pub fn from_nanos_of_day(year: i32, day_of_year: u32, nanos: u64) -> Result<Self, DecimalTimeError> {
+        let decimal_day = nanos as f64 / NANOS_PER_DAY as f64;
+        if nanos >= NANOS_PER_DAY {
+            return Err(DecimalTimeError::DecimalDayOutOfRange(decimal_day));
+        }
+        validate(year, day_of_year, decimal_day)?;
+
+        Ok(DecimalTime {
+            year,
+            day_of_year,
+            decimal_day,
+        })
+    }
+
+    /// Returns the time left until the next midnight, `1.0 - decimal_day`
+    /// days (a full day at midnight itself).
+    pub fn until_next_midnight(&self) -> DecimalDuration {
+        DecimalDuration::from_decimal_days(1.0 - self.decimal_day)
+    }
+
+    /// Returns the time elapsed since the last midnight, `decimal_day` days.
+    pub fn since_last_midnight(&self) -> DecimalDuration {
+        DecimalDuration::from_decimal_days(self.decimal_day)
+    }
+
+    /// Returns the (fractional) number of conventional seconds into the day,
+    /// in [0.0, 86400.0).
+    pub fn seconds_of_day(&self) -> f64 {
+        self.decimal_day * SECONDS_PER_DAY
+    }
+
+    /// Creates a `DecimalTime` from a (fractional) number of conventional
+    /// seconds into the day.
+    ///
+    /// Fails if `secs` is out of [0, 86400) or the date is invalid.
+    pub fn from_seconds_of_day(year: i32, day_of_year: u32, secs: f64) -> Result<Self, DecimalTimeError> {
+        if !(0.0..SECONDS_PER_DAY).contains(&secs) {
+            return Err(DecimalTimeError::DecimalDayOutOfRange(secs / SECONDS_PER_DAY));
+        }
+        // Seconds a hair below 86400 can divide to exactly 1.0
+        let decimal_day = (secs / SECONDS_PER_DAY).min(1.0 - f64::EPSILON / 2.0);
+        validate(year, day_of_year, decimal_day)?;
+
+        Ok(DecimalTime {
+            year,
+            day_of_year,
+            decimal_day,
+        })
+    }
+
+    /// Returns the signed duration from `earlier` to `self`
+    /// (negative if `earlier` is actually later).
+    ///
+    /// # Panics
+    ///
+    /// Panics if either date is invalid.
+    pub fn signed_duration_since(&self, earlier: &DecimalTime) -> DecimalDuration {
+        Self::duration_between_utc(earlier, self).unwrap_or_else(|| {
+            panic!(
+                "cannot measure from {:?} to {:?}: invalid date or i64 microsecond overflow",
+                earlier, self
+            )
+        })
+    }
+
+    /// Returns the signed duration from `a` to `b`, both taken as UTC
+    /// (negative if `b` is earlier).
+    ///
+    /// Returns `None` if either date is invalid or the span overflows
+    /// i64 microseconds.
+    pub fn duration_between_utc(a: &DecimalTime, b: &DecimalTime) -> Option<DecimalDuration> {
+        let a = a.to_naive_datetime_opt()?.and_utc();
+        let b = b.to_naive_datetime_opt()?.and_utc();
+        (b - a).num_microseconds().map(DecimalDuration::from_microseconds)
+    }
+
+    /// Returns `true` if `self` falls on the anniversary of `other` (the same
+    /// calendar month and day, in any year) within `tol` of `other`'s time of day.
+    ///
+    /// A Feb 29 `other` is matched against Feb 28 in non-leap years.
+    /// Returns `false` if either date is invalid.
+    pub fn is_anniversary_of(&self, other: &DecimalTime, tol: DecimalDuration) -> bool {
+        let (Some(this), Some(theirs)) = (self.to_naive_datetime_opt(), other.to_naive_datetime_opt()) else {
+            return false;
+        };
+        let tol = tol.num_microseconds().abs();
+
+        // Check neighbouring years too so a tolerance can reach across New Year.
+        (self.year - 1..=self.year + 1).any(|year| {
+            let day = if theirs.month() == 2 && theirs.day() == 29 && !is_leap_year(year) {
+                28
+            } else {
+                theirs.day()
+            };
+            NaiveDate::from_ymd_opt(year, theirs.month(), day)
+                .map(|date| date.and_time(theirs.time()))
+                .and_then(|candidate| (this - candidate).num_microseconds())
+                .is_some_and(|diff| diff.abs() <= tol)
+        })
+    }
+
+    /// Returns the conventional-time span from `earlier` to `self` as
+    /// `(days, hours, minutes, seconds)`, truncating sub-second remainders.
+    ///
+    /// Returns `None` if either date is invalid or `earlier` is after `self`.
+    pub fn chrono_components_since(&self, earlier: &DecimalTime) -> Option<(i64, u32, u32, u32)> {
+        let span = self.to_naive_datetime_opt()? - earlier.to_naive_datetime_opt()?;
+        if span < chrono::Duration::zero() {
+            return None;
+        }
+
+        let seconds = span.num_seconds();
+        Some((
+            seconds / 86_400,
+            (seconds % 86_400 / 3_600) as u32,
+            (seconds % 3_600 / 60) as u32,
+            (seconds % 60) as u32,
+        ))
+    }
+
+    /// Returns the (possibly fractional) number of days from `epoch` to `self`,
+    /// negative if `self` is before `epoch`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either date is invalid.
+    pub fn to_days_since(&self, epoch: &DecimalTime) -> f64 {
+        self.signed_duration_since(epoch).as_decimal_days()
+    }
+
+    /// Returns the whole and fractional days since 1970-01-01 00:00 UTC as
+    /// one continuous, monotonic value (negative before the epoch), suitable
+    /// for plotting or linear interpolation.
+    ///
+    /// `f64` spacing grows with distance from the epoch: the value resolves
+    /// better than a microsecond for about 140 years either side of 1970,
+    /// roughly 10 microseconds a few thousand years out, and only about a
+    /// millisecond at the edges of chrono's range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+    pub fn to_days_since_epoch(&self) -> f64 {
+        let date = NaiveDate::from_yo_opt(self.year, self.day_of_year).unwrap_or_else(|| {
+            panic!(
+                "Invalid day_of_year={} for year={}",
+                self.day_of_year, self.year
+            )
+        });
+        (date.num_days_from_ce() - UNIX_EPOCH_DAYS_FROM_CE) as f64 + self.decimal_day
+    }
+
+    /// The inverse of `to_days_since_epoch`, subject to the same precision limits.
+    ///
+    /// Returns `None` if `days` is not finite or out of chrono's range.
+    pub fn from_days_since_epoch(days: f64) -> Option<DecimalTime> {
+        if !days.is_finite() {
+            return None;
+        }
+        let mut whole_days = math::floor(days);
+        let mut decimal_day = days - whole_days;
+        if decimal_day >= 1.0 {
+            whole_days += 1.0;
+            decimal_day = 0.0;
+        }
+        if whole_days.abs() > i32::MAX as f64 / 2.0 {
+            return None;
+        }
+        let date = NaiveDate::from_num_days_from_ce_opt(UNIX_EPOCH_DAYS_FROM_CE + whole_days as i32)?;
+        Some(DecimalTime::new_unchecked(date.year(), date.ordinal(), decimal_day))
+    }
+
+    /// Returns the time `t` of the way from `a` to `b`, interpolating
+    /// linearly on `to_days_since_epoch` (so `t = 0.0` is `a` and
+    /// `t = 1.0` is `b`, up to its precision limits).
+    ///
+    /// `t` outside `[0, 1]` extrapolates beyond `a` or `b` rather than clamping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either date is invalid, or if `t` is not finite or the
+    /// result is out of range.
+    pub fn lerp(a: &DecimalTime, b: &DecimalTime, t: f64) -> DecimalTime {
+        let (start, end) = (a.to_days_since_epoch(), b.to_days_since_epoch());
+        Self::from_days_since_epoch(start + (end - start) * t)
+            .unwrap_or_else(|| panic!("interpolating with t={} is out of range", t))
+    }
+
+    /// Adds a `chrono::Duration`, carrying across day and year boundaries.
+    ///
+    /// Returns `None` if `self` is invalid or the result is out of range.
+    pub fn checked_add(&self, d: chrono::Duration) -> Option<DecimalTime> {
+        let ndt = self.to_naive_datetime_opt()?.checked_add_signed(d)?;
+        Some(Self::from_naive_datetime(ndt))
+    }
+
+    /// Subtracts a `chrono::Duration`, carrying across day and year boundaries.
+    ///
+    /// Returns `None` if `self` is invalid or the result is out of range.
+    pub fn checked_sub(&self, d: chrono::Duration) -> Option<DecimalTime> {
+        let ndt = self.to_naive_datetime_opt()?.checked_sub_signed(d)?;
+        Some(Self::from_naive_datetime(ndt))
+    }
+
+    /// Moves the calendar date forward by `n` days (backwards if negative),
+    /// keeping `decimal_day` exactly as it is.
+    ///
+    /// Returns `None` if `self` is invalid or the date leaves chrono's range.
+    pub fn checked_add_days(&self, n: i64) -> Option<DecimalTime> {
+        if !self.is_valid() {
+            return None;
+        }
+        let date = NaiveDate::from_yo_opt(self.year, self.day_of_year)?;
+        let days = chrono::Days::new(n.unsigned_abs());
+        let date = if n >= 0 {
+            date.checked_add_days(days)?
+        } else {
+            date.checked_sub_days(days)?
+        };
+        Some(DecimalTime::new_unchecked(date.year(), date.ordinal(), self.decimal_day))
+    }
+
+    /// Moves the calendar date back by `n` days, like `checked_add_days(-n)`.
+    pub fn checked_sub_days(&self, n: i64) -> Option<DecimalTime> {
+        self.checked_add_days(n.checked_neg()?)
+    }
+
+    /// Treats the fields as wall-clock time at offset `from` and returns the
+    /// same instant as wall-clock time at offset `to`, carrying across day
+    /// and year boundaries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid or the shifted time is out of range.
+    pub fn shift_offset(&self, from: FixedOffset, to: FixedOffset) -> DecimalTime {
+        let shift = to.local_minus_utc() as i64 - from.local_minus_utc() as i64;
+        self.checked_add(chrono::Duration::seconds(shift))
+            .expect("invalid date or shifted time out of range")
+    }
+
+    /// Adds a `chrono::Duration`, clamping to `DecimalTime::MAX` (or `MIN`
+    /// for negative durations) instead of overflowing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+    pub fn saturating_add(&self, d: chrono::Duration) -> DecimalTime {
+        self.checked_add(d).unwrap_or_else(|| self.saturate_towards(d < chrono::Duration::zero()))
+    }
+
+    /// Subtracts a `chrono::Duration`, clamping to `DecimalTime::MIN` (or `MAX`
+    /// for negative durations) instead of overflowing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+    pub fn saturating_sub(&self, d: chrono::Duration) -> DecimalTime {
+        self.checked_sub(d).unwrap_or_else(|| self.saturate_towards(d > chrono::Duration::zero()))
+    }
+
+    /// Picks the bound an overflowing `checked_add`/`checked_sub` clamps to,
+    /// panicking if the overflow was really an invalid date.
+    fn saturate_towards(&self, backwards: bool) -> DecimalTime {
+        if self.to_naive_datetime_opt().is_none() {
+            panic!(
+                "Invalid day_of_year={} for year={}",
+                self.day_of_year, self.year
+            );
+        }
+        if backwards {
+            Self::MIN
+        } else {
+            Self::MAX
+        }
+    }
+
+    /// Adds a (possibly fractional) number of decimal hours (1/10 of a day),
+    /// rounded to the nearest microsecond.
+    ///
+    /// Returns `None` if `hours` is not finite, `self` is invalid, or the
+    /// result is out of range.
+    pub fn checked_add_decimal_hours(&self, hours: f64) -> Option<DecimalTime> {
+        self.checked_add_decimal_days(hours / 10.0)
+    }
+
+    /// Adds a (possibly fractional) number of decimal minutes (1/1000 of a day).
+    ///
+    /// Returns `None` like `checked_add_decimal_hours`.
+    pub fn checked_add_decimal_minutes(&self, minutes: f64) -> Option<DecimalTime> {
+        self.checked_add_decimal_days(minutes / 1_000.0)
+    }
+
+    /// Adds a (possibly fractional) number of decimal seconds (1/100000 of a day).
+    ///
+    /// Returns `None` like `checked_add_decimal_hours`.
+    pub fn checked_add_decimal_seconds(&self, seconds: f64) -> Option<DecimalTime> {
+        self.checked_add_decimal_days(seconds / 100_000.0)
+    }
+
+    fn checked_add_decimal_days(&self, days: f64) -> Option<DecimalTime> {
+        let micros = math::round(days * MICROS_PER_DAY as f64);
+        if !micros.is_finite() || micros.abs() >= i64::MAX as f64 {
+            return None;
+        }
+        self.checked_add(chrono::Duration::microseconds(micros as i64))
+    }
+
+    /// Adds `n` decimal minutes (1/1000 of a day, i.e. 86.4 seconds each),
+    /// wrapping across day and year boundaries like `checked_add`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is invalid or the result is out of range.
+    pub fn add_decimal_minutes(&self, n: i64) -> DecimalTime {
+        n.checked_mul(MICROS_PER_DECIMAL_MINUTE)
+            .and_then(|micros| self.checked_add(chrono::Duration::microseconds(micros)))
+            .expect("adding decimal minutes overflowed")
+    }
+
+    /// Adds `n` Swatch beats (1/1000 of a day, the same length as a decimal minute).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is invalid or the result is out of range.
+    pub fn add_beats(&self, n: i64) -> DecimalTime {
+        self.add_decimal_minutes(n)
+    }
+
+    /// Returns the in-range equivalent of a value with out-of-range fields,
+    /// carrying whole days out of `decimal_day` into `day_of_year` and excess
+    /// days into `year`, through leap years and in either direction.
+    ///
+    /// For example `{2025, 365, 1.5}` becomes `{2026, 1, 0.5}`, and
+    /// `{2025, 1, -0.5}` becomes `{2024, 366, 0.5}`. Day 0 is the last day
+    /// of the previous year.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decimal_day` is not finite or the year overflows `i32`.
+    pub fn normalize(&self) -> DecimalTime {
+        if !self.decimal_day.is_finite() {
+            panic!("`decimal_day` must be finite. Received: {}", self.decimal_day);
+        }
+
+        let mut carry = math::floor(self.decimal_day);
+        let mut decimal_day = self.decimal_day - carry;
+        if decimal_day >= 1.0 {
+            carry += 1.0;
+            decimal_day = 0.0;
+        }
+
+        // Zero-based day offset from January 1st of `year`
+        let offset = self.day_of_year as i64 - 1 + carry as i64;
+        // Jump whole 400-year Gregorian cycles first, then walk single years
+        let mut year = self.year as i64 + 400 * offset.div_euclid(DAYS_PER_400_YEARS);
+        let mut offset = offset.rem_euclid(DAYS_PER_400_YEARS);
+        let year_length = |year: i64| days_in_year(year.rem_euclid(400) as i32) as i64;
+        while offset >= year_length(year) {
+            offset -= year_length(year);
+            year += 1;
+        }
+
+        DecimalTime {
+            year: i32::try_from(year).expect("normalized year overflowed i32"),
+            day_of_year: offset as u32 + 1,
+            decimal_day,
+        }
+    }
+
+    /// Adds `delta` to `decimal_day` and wraps the result into [0,1),
+    /// treating the day as a pure cycle: `year` and `day_of_year` are unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delta` is not finite.
+    pub fn wrap_fraction(self, delta: f64) -> DecimalTime {
+        if !delta.is_finite() {
+            panic!("`delta` must be finite. Received: {}", delta);
+        }
+        let sum = self.decimal_day + delta;
+        let mut decimal_day = sum - math::floor(sum);
+        if decimal_day >= 1.0 {
+            // a tiny negative `sum` rounds up to 1.0
+            decimal_day = 0.0;
+        }
+        DecimalTime { decimal_day, ..self }
+    }
+
+    /// Lazily yields the ten decimal-hour ticks (0.0, 0.1, …, 0.9)
+    /// of every day in `year`, respecting leap years.
+    pub fn decimal_hour_ticks_for_year(year: i32) -> impl Iterator<Item = DecimalTime> {
+        (1..=days_in_year(year)).flat_map(move |day_of_year| {
+            (0..10).map(move |hour| DecimalTime {
+                year,
+                day_of_year,
+                decimal_day: hour as f64 / 10.0,
+            })
+        })
+    }
+
+    /// Returns the ten decimal-hour marks (0.0, 0.1, …, 0.9) of one day.
+    ///
+    /// Fails if `day_of_year` is out of range for `year`.
+    pub fn decimal_hour_marks(year: i32, day_of_year: u32) -> Result<[DecimalTime; 10], DecimalTimeError> {
+        validate(year, day_of_year, 0.0)?;
+        Ok(core::array::from_fn(|hour| {
+            DecimalTime::new_unchecked(year, day_of_year, hour as f64 / 10.0)
+        }))
+    }
+
+    /// Returns the hundred decimal-minute marks of one decimal hour
+    /// (`decimal_hour` 0–9) of a day: for hour 7, 0.700, 0.701, …, 0.799.
+    ///
+    /// Fails if `day_of_year` is out of range for `year` or `decimal_hour`
+    /// is above 9.
+    pub fn decimal_minute_marks(
+        year: i32,
+        day_of_year: u32,
+        decimal_hour: u32,
+    ) -> Result<[DecimalTime; 100], DecimalTimeError> {
+        if decimal_hour > 9 {
+            return Err(DecimalTimeError::ComponentOutOfRange {
+                component: "hour",
+                value: decimal_hour as f64,
+            });
+        }
+        validate(year, day_of_year, 0.0)?;
+        Ok(core::array::from_fn(|minute| {
+            let minutes = decimal_hour as usize * 100 + minute;
+            DecimalTime::new_unchecked(year, day_of_year, minutes as f64 / 1_000.0)
+        }))
+    }
+
+    /// Returns how far through the year this time is, in [0,1).
+    ///
+    /// Leap years use 366 days in the denominator.
+    pub fn fraction_of_year(&self) -> f64 {
+        ((self.day_of_year - 1) as f64 + self.decimal_day) / days_in_year(self.year) as f64
+    }
+
+    /// Inverse of `fraction_of_year`.
+    ///
+    /// Returns `None` if `frac` is out of [0,1) or `year` is outside
+    /// `MIN_YEAR..=MAX_YEAR`.
+    pub fn from_fraction_of_year(year: i32, frac: f64) -> Option<DecimalTime> {
+        if !(0.0..1.0).contains(&frac) || !(Self::MIN_YEAR..=Self::MAX_YEAR).contains(&year) {
+            return None;
+        }
+
+        let days = frac * days_in_year(year) as f64;
+        let whole_days = math::floor(days);
+        Some(DecimalTime {
+            year,
+            day_of_year: whole_days as u32 + 1,
+            decimal_day: days - whole_days,
+        })
+    }
+
+    /// Creates a `DecimalTime` from a fractional year such as `2025.5`:
+    /// the integer part is the year and the fractional part goes through
+    /// `from_fraction_of_year`, so leap years are respected.
+    ///
+    /// Returns `None` if `y` is not finite or its year is outside
+    /// `MIN_YEAR..=MAX_YEAR`.
+    pub fn from_decimal_year(y: f64) -> Option<DecimalTime> {
+        if !y.is_finite() {
+            return None;
+        }
+        let year = math::floor(y);
+        if !(Self::MIN_YEAR as f64..=Self::MAX_YEAR as f64).contains(&year) {
+            return None;
+        }
+        // Clamp a tiny negative fraction that rounds up to 1.0
+        let frac = (y - year).min(1.0 - f64::EPSILON / 2.0);
+        Self::from_fraction_of_year(year as i32, frac)
+    }
+
+    /// Inverse of `from_decimal_year`: `year + fraction_of_year()`.
+    pub fn to_decimal_year(&self) -> f64 {
+        self.year as f64 + self.fraction_of_year()
+    }
+
+    /// Rounds `decimal_day` to `digits` fractional places.
+    ///
+    /// Rounding up from the end of a day carries into midnight of the next
+    /// day (and year). Returns `None` if the date is invalid or the carry
+    /// would exceed `max_value()`. More than 17 places is past `f64`
+    /// precision and leaves the value unchanged.
+    pub fn checked_round(&self, digits: u32, mode: RoundingMode) -> Option<DecimalTime> {
+        let date = NaiveDate::from_yo_opt(self.year, self.day_of_year)?;
+        if digits > math::MAX_FRACTION_DIGITS {
+            return Some(*self);
+        }
+        let scale = math::pow10(digits);
+        let scaled = self.decimal_day * scale;
+        let rounded = match mode {
+            RoundingMode::Nearest => math::round(scaled),
+            RoundingMode::Up => math::ceil(scaled),
+            RoundingMode::Down => math::floor(scaled),
+        };
+
+        if rounded >= scale {
+            let next = date.succ_opt()?;
+            return Some(DecimalTime {
+                year: next.year(),
+                day_of_year: next.ordinal(),
+                decimal_day: 0.0,
+            });
+        }
+
+        Some(DecimalTime {
+            year: self.year,
+            day_of_year: self.day_of_year,
+            decimal_day: rounded / scale,
+        })
+    }
+
+    /// Returns the decimal-day error introduced by storing this value rounded
+    /// to `stored_digits` fractional places (`RoundingMode::Nearest`).
+    ///
+    /// The result never exceeds half a unit in the last stored place,
+    /// `0.5 * 10^-stored_digits`. More than 17 digits loses nothing.
+    pub fn precision_loss_report(&self, stored_digits: u32) -> f64 {
+        if stored_digits > math::MAX_FRACTION_DIGITS {
+            return 0.0;
+        }
+        let scale = math::pow10(stored_digits);
+        let stored = math::round(self.decimal_day * scale) / scale;
+        math::abs(stored - self.decimal_day)
+    }
+
+    /// Returns the signed microsecond difference between `dt` and its
+    /// round trip through `from_naive_datetime` and `to_naive_datetime`
+    /// (positive if the round trip lands later).
+    ///
+    /// Sub-microsecond nanoseconds in `dt` are dropped by the conversion
+    /// and are not counted.
+    pub fn round_trip_error(dt: NaiveDateTime) -> i64 {
+        let round_trip = Self::from_naive_datetime(dt).to_naive_datetime();
+        round_trip.signed_duration_since(dt).num_microseconds().unwrap_or(i64::MAX)
+    }
+
+    /// Rounds `decimal_day` to `digits` fractional places, carrying into
+    /// the next day when rounding up from the end of a day.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid or the carry exceeds `max_value()`.
+    pub fn round_to_places(&self, digits: u32, mode: RoundingMode) -> DecimalTime {
+        self.checked_round(digits, mode)
+            .expect("rounding carried past the representable range")
+    }
+
+    /// Rounds to the nearest decimal minute (1/1000 of a day), carrying
+    /// into the next day like `round_to_places`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid or the carry exceeds `max_value()`.
+    pub fn snap_to_decimal_minute(&self) -> DecimalTime {
+        self.round_to_places(3, RoundingMode::Nearest)
+    }
+
+    /// Rounds to the nearest decimal second (1/100000 of a day), carrying
+    /// into the next day like `round_to_places`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid or the carry exceeds `max_value()`.
+    pub fn snap_to_decimal_second(&self) -> DecimalTime {
+        self.round_to_places(5, RoundingMode::Nearest)
+    }
+
+    /// Returns which ten-day "décade" (1-based) `day_of_year` falls in.
+    ///
+    /// Day 73 is in décade 8. The year ends with a partial décade 37
+    /// holding days 361–365 (or 361–366 in a leap year).
+    pub fn decade_of_year(&self) -> u32 {
+        (self.day_of_year - 1) / 10 + 1
+    }
+
+    /// Returns the calendar quarter (1–4): January–March is quarter 1.
+    ///
+    /// Quarters follow the Gregorian months rather than splitting the year
+    /// evenly, so they match conventional reporting periods; in a leap year
+    /// Feb 29 shifts each later boundary by a day.
+    pub fn quarter(&self) -> u8 {
+        let leap_day = is_leap_year(self.year) as u32;
+        match self.day_of_year {
+            day if day <= 90 + leap_day => 1,
+            day if day <= 181 + leap_day => 2,
+            day if day <= 273 + leap_day => 3,
+            _ => 4,
+        }
+    }
+
+    /// Returns the calendar half of the year (1–2): January–June is half 1.
+    pub fn half(&self) -> u8 {
+        if self.quarter() <= 2 {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Returns the position (1–10) of `day_of_year` within its décade.
+    ///
+    /// Day 73 is day 3 of its décade. The partial décade at year-end only
+    /// reaches day 5 (or 6 in a leap year).
+    pub fn day_of_decade(&self) -> u32 {
+        (self.day_of_year - 1) % 10 + 1
+    }
+
+    /// Returns the next day (strictly after this one) whose `day_of_decade`
+    /// is `target`, keeping `decimal_day`.
+    ///
+    /// Positions past the end of the year's partial décade are skipped, so
+    /// asking for day 8 from day 362 lands on day 8 of the next year.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target` is out of 1..=10, the date is invalid, or the
+    /// result is out of range.
+    pub fn next_day_of_decade(&self, target: u32) -> DecimalTime {
+        if !(1..=10).contains(&target) {
+            panic!("`target` must be in [1..=10]. Received: {}", target);
+        }
+
+        let mut next = *self;
+        loop {
+            next = next
+                .checked_add_days(1)
+                .expect("invalid date or next day of décade out of range");
+            if next.day_of_decade() == target {
+                return next;
+            }
+        }
+    }
+
+    /// Returns the Gregorian day of the week.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year).
+    pub fn weekday(&self) -> chrono::Weekday {
+        self.weekday_opt().unwrap_or_else(|| {
+            panic!(
+                "Invalid day_of_year={} for year={}",
+                self.day_of_year, self.year
+            )
+        })
+    }
+
+    /// Returns the Gregorian day of the week, or `None` if the date is invalid.
+    pub fn weekday_opt(&self) -> Option<chrono::Weekday> {
+        NaiveDate::from_yo_opt(self.year, self.day_of_year).map(|date| date.weekday())
+    }
+
+    /// Returns the conventional wall-clock time of day as
+    /// `(hours, minutes, seconds, microseconds)`.
+    ///
+    /// Uses the same microsecond rounding as `to_naive_datetime`, so it always
+    /// agrees with `to_naive_datetime().time()`.
+    pub fn wall_clock(&self) -> (u32, u32, u32, u32) {
+        let micros = self.microseconds_into_day() % MICROS_PER_DAY as u64;
+        let seconds = micros / 1_000_000;
+
+        (
+            (seconds / 3_600) as u32,
+            (seconds / 60 % 60) as u32,
+            (seconds % 60) as u32,
+            (micros % 1_000_000) as u32,
+        )
+    }
 
-        // year
-        output = output.replace("%Y", &self.year.to_string());
+    /// Returns the angle of a single hand that goes round once per day,
+    /// `decimal_day * 360.0`.
+    ///
+    /// Like all the hand angles, 0° points straight up (midnight) and
+    /// angles increase clockwise, in [0, 360).
+    pub fn hand_angle_degrees(&self) -> f64 {
+        self.decimal_day * 360.0
+    }
+
+    /// Returns the angle of the hour hand on a 10-hour dial: the
+    /// (fractional) decimal hour times 36°.
+    ///
+    /// The dial holds the whole day, so this matches `hand_angle_degrees`.
+    pub fn decimal_hour_angle_degrees(&self) -> f64 {
+        self.decimal_day * 10.0 * 36.0
+    }
+
+    /// Returns the angle of the minute hand on a 100-minute dial: how far
+    /// through the current decimal hour this time is, times 360°.
+    pub fn decimal_minute_angle_degrees(&self) -> f64 {
+        let hours = self.decimal_day * 10.0;
+        (hours - math::floor(hours)) * 360.0
+    }
+
+    /// Breaks the time of day down into decimal clock units
+    /// (10 hours, 100 minutes, 100 seconds) and conventional `H:M:S`.
+    pub fn components(&self) -> DecimalComponents {
+        let total_decimal_seconds = self.decimal_day * 100_000.0;
+        let decimal_hour = math::floor(total_decimal_seconds / 10_000.0) as u32;
+        let decimal_minute = (math::floor(total_decimal_seconds / 100.0) as u32) % 100;
+        let decimal_second =
+            total_decimal_seconds - (decimal_hour * 10_000 + decimal_minute * 100) as f64;
+
+        let (hour, minute, second, _) = self.wall_clock();
+
+        DecimalComponents {
+            year: self.year,
+            day_of_year: self.day_of_year,
+            decimal_hour,
+            decimal_minute,
+            decimal_second,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Rebuilds a `DecimalTime` from the decimal clock units of `c`.
+    ///
+    /// The conventional `hour`/`minute`/`second` fields are redundant and ignored.
+    pub fn from_components(c: &DecimalComponents) -> Result<DecimalTime, DecimalTimeError> {
+        if c.decimal_hour > 9 {
+            return Err(DecimalTimeError::ComponentOutOfRange {
+                component: "hour",
+                value: c.decimal_hour as f64,
+            });
+        }
+        if c.decimal_minute > 99 {
+            return Err(DecimalTimeError::ComponentOutOfRange {
+                component: "minute",
+                value: c.decimal_minute as f64,
+            });
+        }
+        if !(0.0..100.0).contains(&c.decimal_second) {
+            return Err(DecimalTimeError::ComponentOutOfRange {
+                component: "second",
+                value: c.decimal_second,
+            });
+        }
+
+        let total_decimal_seconds =
+            (c.decimal_hour * 10_000 + c.decimal_minute * 100) as f64 + c.decimal_second;
+        let decimal_day = total_decimal_seconds / 100_000.0;
+        validate(c.year, c.day_of_year, decimal_day)?;
+
+        Ok(DecimalTime {
+            year: c.year,
+            day_of_year: c.day_of_year,
+            decimal_day,
+        })
+    }
+
+    /// Returns a key identifying the instant at microsecond resolution
+    /// (microseconds since 0001-01-01 in the proleptic Gregorian calendar).
+    ///
+    /// Out-of-range fields are carried arithmetically, so `{2025, 365, 1.0}`
+    /// and `{2026, 1, 0.0}` share a key. For valid values the key orders
+    /// the same way as `Ord`, up to microsecond rounding.
+    pub fn sort_key(&self) -> i128 {
+        let prior_years = self.year as i64 - 1;
+        let days_before_year = 365 * prior_years + prior_years.div_euclid(4)
+            - prior_years.div_euclid(100)
+            + prior_years.div_euclid(400);
+        let days = days_before_year + self.day_of_year as i64 - 1;
+        let micros = math::round(self.decimal_day * MICROS_PER_DAY as f64) as i64;
+
+        days as i128 * MICROS_PER_DAY as i128 + micros as i128
+    }
+
+    /// Packs the value into one integer that reads as `YYYYDDDMMMMMMMMMMM`:
+    /// `year * 10^14 + day_of_year * 10^11 + microsecond of the day`.
+    ///
+    /// For example noon on day 73 of 2025 packs to `202507343200000000`.
+    /// Packed values sort in the same order as `Ord` (negative years
+    /// included), making them usable as integer database keys. The time of
+    /// day is kept at microsecond precision, rounded like `nanos_of_day`
+    /// (never up into the next day).
+    pub fn to_packed(&self) -> i128 {
+        let micros = self.microseconds_into_day().min(MICROS_PER_DAY as u64 - 1);
+        self.year as i128 * PACKED_YEAR + self.day_of_year as i128 * PACKED_DAY + micros as i128
+    }
+
+    /// Unpacks a value written by `to_packed`.
+    ///
+    /// Returns `None` if any packed field is out of range for its date.
+    pub fn from_packed(v: i128) -> Option<DecimalTime> {
+        let year = i32::try_from(v.div_euclid(PACKED_YEAR)).ok()?;
+        let rest = v.rem_euclid(PACKED_YEAR);
+        let day_of_year = (rest / PACKED_DAY) as u32;
+        let micros = (rest % PACKED_DAY) as i64;
+        if micros >= MICROS_PER_DAY {
+            return None;
+        }
+        let decimal_day = micros as f64 / MICROS_PER_DAY as f64;
+        validate(year, day_of_year, decimal_day).ok()?;
+        Some(DecimalTime::new_unchecked(year, day_of_year, decimal_day))
+    }
+
+    /// Encodes the value as 16 big-endian bytes: `year` (4), `day_of_year`
+    /// (4) and the microsecond of the day (8), rounded like `to_packed`.
+    ///
+    /// The year's sign bit is flipped so that comparing the raw bytes
+    /// orders values chronologically, negative years included.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let micros = self.microseconds_into_day().min(MICROS_PER_DAY as u64 - 1);
+        let mut bytes = [0; 16];
+        bytes[..4].copy_from_slice(&((self.year as u32) ^ SIGN_BIT).to_be_bytes());
+        bytes[4..8].copy_from_slice(&self.day_of_year.to_be_bytes());
+        bytes[8..].copy_from_slice(&micros.to_be_bytes());
+        bytes
+    }
+
+    /// Decodes bytes written by `to_bytes`.
+    ///
+    /// Returns `None` if any decoded field is out of range for its date.
+    pub fn from_bytes(b: &[u8; 16]) -> Option<DecimalTime> {
+        let [y0, y1, y2, y3, d0, d1, d2, d3, m @ ..] = *b;
+        let year = (u32::from_be_bytes([y0, y1, y2, y3]) ^ SIGN_BIT) as i32;
+        let day_of_year = u32::from_be_bytes([d0, d1, d2, d3]);
+        let micros = u64::from_be_bytes(m);
+        if micros >= MICROS_PER_DAY as u64 {
+            return None;
+        }
+        let decimal_day = micros as f64 / MICROS_PER_DAY as f64;
+        validate(year, day_of_year, decimal_day).ok()?;
+        Some(DecimalTime::new_unchecked(year, day_of_year, decimal_day))
+    }
+
+    /// Returns the distinct instants in `times`, comparing via `sort_key`
+    /// and keeping the first-seen representation of each.
+    #[cfg(feature = "alloc")]
+    pub fn unique_instants(times: &[DecimalTime]) -> Vec<DecimalTime> {
+        let mut seen = alloc::collections::BTreeSet::new();
+        times
+            .iter()
+            .filter(|t| seen.insert(t.sort_key()))
+            .copied()
+            .collect()
+    }
+
+    /// Returns `true` if `times` never goes backwards in time
+    /// (compared as instants via `sort_key`). Empty slices are sorted.
+    pub fn is_sorted_ascending(times: &[DecimalTime]) -> bool {
+        Self::first_out_of_order(times).is_none()
+    }
+
+    /// Returns the index of the first element that is earlier than its
+    /// predecessor (compared as instants via `sort_key`).
+    pub fn first_out_of_order(times: &[DecimalTime]) -> Option<usize> {
+        times
+            .windows(2)
+            .position(|pair| pair[1].sort_key() < pair[0].sort_key())
+            .map(|i| i + 1)
+    }
 
-        // day_of_year
-        let day_str = format!("{}", self.day_of_year);
-        output = output.replace("%d", &day_str);
+    /// Returns the earliest and latest instants in `iter` as `(min, max)`
+    /// in a single pass, compared via `sort_key`, or `None` if it is empty.
+    ///
+    /// Ties keep the first-seen element for `min` and the last-seen for `max`.
+    pub fn bounds<I: IntoIterator<Item = DecimalTime>>(iter: I) -> Option<(DecimalTime, DecimalTime)> {
+        let mut iter = iter.into_iter();
+        let first = iter.next()?;
+        Some(iter.fold((first, first), |(min, max), t| {
+            let key = t.sort_key();
+            (
+                if key < min.sort_key() { t } else { min },
+                if key >= max.sort_key() { t } else { max },
+            )
+        }))
+    }
+
+    /// Returns the circular mean of the `decimal_day` values in `times`, in
+    /// [0,1): each fraction is a point on the unit circle, and the result is
+    /// the angle of their average. So 0.99 and 0.01 average to 0.0, not 0.5.
+    ///
+    /// Returns `None` for an empty slice, or when the points cancel out
+    /// (e.g. 0.25 and 0.75) and the mean is undefined.
+    pub fn mean_time_of_day(times: &[DecimalTime]) -> Option<f64> {
+        const TAU: f64 = core::f64::consts::TAU;
 
-        // decimal fraction
-        if output.contains("%f") {
-            let frac = format!("{}", self.decimal_day);
-            output = output.replace("%f", &frac.trim_start_matches('0'));
+        let (sum_cos, sum_sin) = times.iter().fold((0.0, 0.0), |(c, s), t| {
+            let angle = t.decimal_day * TAU;
+            (c + math::cos(angle), s + math::sin(angle))
+        });
+        let n = times.len() as f64;
+        if times.is_empty() || math::hypot(sum_cos, sum_sin) / n < 1e-9 {
+            return None;
         }
 
-        output
+        let mean = math::atan2(sum_sin, sum_cos) / TAU;
+        let mean = if mean < 0.0 { mean + 1.0 } else { mean };
+        // -0.0 or a hair below zero can round up to exactly 1.0
+        Some(if mean >= 1.0 { 0.0 } else { mean })
+    }
+
+    /// Returns each consecutive pair in `times` whose spacing exceeds `min_gap`.
+    ///
+    /// `times` is assumed to be sorted ascending; unsorted input yields
+    /// negative spacings, which never count as gaps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any date is invalid.
+    #[cfg(feature = "alloc")]
+    pub fn find_gaps(times: &[DecimalTime], min_gap: DecimalDuration) -> Vec<(DecimalTime, DecimalTime)> {
+        times
+            .windows(2)
+            .filter(|pair| pair[1].signed_duration_since(&pair[0]) > min_gap)
+            .map(|pair| (pair[0], pair[1]))
+            .collect()
+    }
+
+    /// Lays a regular grid of points from `start` to `end` (inclusive),
+    /// `step` apart, and pairs each point with the index of the nearest
+    /// sample in `times` that lies within half a step of it.
+    ///
+    /// Ties go to the earlier index; points with no sample close enough
+    /// get `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive or any date is invalid.
+    #[cfg(feature = "alloc")]
+    pub fn resample_to_grid(
+        times: &[DecimalTime],
+        step: DecimalDuration,
+        start: &DecimalTime,
+        end: &DecimalTime,
+    ) -> Vec<(DecimalTime, Option<usize>)> {
+        if step <= DecimalDuration::ZERO {
+            panic!("`step` must be positive. Received: {}", step);
+        }
+        let half_step = step.num_microseconds() / 2;
+
+        let mut grid = Vec::new();
+        let mut point = Some(*start);
+        while let Some(p) = point.filter(|p| p <= end) {
+            let nearest = times
+                .iter()
+                .enumerate()
+                .map(|(i, t)| (i, t.signed_duration_since(&p).num_microseconds().unsigned_abs()))
+                .filter(|&(_, distance)| distance <= half_step as u64)
+                .min_by_key(|&(_, distance)| distance)
+                .map(|(i, _)| i);
+            grid.push((p, nearest));
+            point = p.checked_add(step.to_chrono());
+        }
+        grid
+    }
+
+    /// Returns `true` if both times fall on the same calendar day,
+    /// ignoring `decimal_day`.
+    pub fn same_day(&self, other: &DecimalTime) -> bool {
+        self.year == other.year && self.day_of_year == other.day_of_year
+    }
+
+    /// Orders two times by calendar day only (`year`, then `day_of_year`),
+    /// ignoring `decimal_day`.
+    pub fn cmp_date(&self, other: &DecimalTime) -> core::cmp::Ordering {
+        (self.year, self.day_of_year).cmp(&(other.year, other.day_of_year))
+    }
+
+    /// Splits the half-open span `[start, end)` at each New Year, returning
+    /// `(year, from, to)` for every year the span touches.
+    ///
+    /// Each piece is half-open too, so a piece that runs to the end of its
+    /// year ends at midnight on day 1 of the next year. Returns an empty
+    /// `Vec` if `end <= start`.
+    #[cfg(feature = "alloc")]
+    pub fn split_by_year(start: &DecimalTime, end: &DecimalTime) -> Vec<(i32, DecimalTime, DecimalTime)> {
+        let mut pieces = Vec::new();
+        let mut from = *start;
+        while from < *end {
+            let next_year = DecimalTime::new_unchecked(from.year + 1, 1, 0.0);
+            let to = next_year.min(*end);
+            pieces.push((from.year, from, to));
+            from = to;
+        }
+        pieces
+    }
+
+    /// Compares `year` and `day_of_year` exactly and `decimal_day` within `epsilon`.
+    ///
+    /// Useful after conversions that introduce tiny floating-point error;
+    /// `1e-9` (roughly 86 microseconds) is a sensible default.
+    pub fn approx_eq(&self, other: &DecimalTime, epsilon: f64) -> bool {
+        self.year == other.year
+            && self.day_of_year == other.day_of_year
+            && math::abs(self.decimal_day - other.decimal_day) <= epsilon
+    }
+
+    /// Returns the earliest `DecimalTime` at or after `self` that falls inside
+    /// the daily window `[start_frac, end_frac)`.
+    ///
+    /// - inside the window => `self`
+    /// - before the window => today's `start_frac`
+    /// - past the window => tomorrow's `start_frac` (carrying into the next year)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start_frac` is out of [0,1), if `start_frac > end_frac`,
+    /// or if the date is invalid.
+    pub fn next_available_in_window(&self, start_frac: f64, end_frac: f64) -> DecimalTime {
+        if start_frac > end_frac {
+            panic!(
+                "`start_frac` must not exceed `end_frac`. Received: {} > {}",
+                start_frac, end_frac
+            );
+        }
+
+        if self.decimal_day < start_frac {
+            return Self::new(self.year, self.day_of_year, start_frac);
+        }
+        if self.decimal_day < end_frac {
+            return *self;
+        }
+
+        let tomorrow = self
+            .to_naive_datetime()
+            .date()
+            .succ_opt()
+            .expect("next day is out of range");
+        Self::new(tomorrow.year(), tomorrow.ordinal(), start_frac)
+    }
+
+}
+
+impl PartialEq for DecimalTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == core::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for DecimalTime {}
+
+impl PartialOrd for DecimalTime {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DecimalTime {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.year
+            .cmp(&other.year)
+            .then(self.day_of_year.cmp(&other.day_of_year))
+            // `+ 0.0` turns `-0.0` into `0.0`, which `validate` also accepts as midnight
+            .then((self.decimal_day + 0.0).total_cmp(&(other.decimal_day + 0.0)))
+    }
+}
+
+/// Equal when `self` converts (via `to_naive_datetime_opt`, which rounds
+/// `decimal_day` to the nearest microsecond) to the same instant as `other`
+/// truncated to microseconds. So a `NaiveDateTime` with sub-microsecond
+/// nanoseconds still equals the `DecimalTime` built from it, and an invalid
+/// `DecimalTime` equals nothing.
+impl PartialEq<NaiveDateTime> for DecimalTime {
+    fn eq(&self, other: &NaiveDateTime) -> bool {
+        let truncated = other
+            .with_nanosecond(other.nanosecond() / 1_000 * 1_000)
+            .expect("truncating nanoseconds stays in range");
+        self.to_naive_datetime_opt() == Some(truncated)
+    }
+}
+
+/// Same as `DecimalTime == NaiveDateTime`.
+impl PartialEq<DecimalTime> for NaiveDateTime {
+    fn eq(&self, other: &DecimalTime) -> bool {
+        other == self
+    }
+}
+
+/// Fallible `from_naive_datetime`: fails instead of panicking if the time of
+/// day cannot be expressed as a fraction in [0,1) (a leap second at the end
+/// of the day).
+///
+/// There is no infallible `From` impl, as it would clash with this one;
+/// use `from_naive_datetime` for that.
+impl TryFrom<NaiveDateTime> for DecimalTime {
+    type Error = DecimalTimeError;
+
+    fn try_from(dt: NaiveDateTime) -> Result<Self, Self::Error> {
+        Self::from_naive_time(dt.year(), dt.ordinal(), dt.time())
+    }
+}
+
+/// Fallible `from_datetime_utc`, see `TryFrom<NaiveDateTime>`.
+impl TryFrom<DateTime<Utc>> for DecimalTime {
+    type Error = DecimalTimeError;
+
+    fn try_from(dt: DateTime<Utc>) -> Result<Self, Self::Error> {
+        Self::try_from(dt.naive_utc())
+    }
+}
+
+/// Fallible `to_naive_datetime`: fails on an out-of-range `decimal_day`
+/// or a `day_of_year` (or `year`) that chrono cannot represent.
+impl TryFrom<DecimalTime> for NaiveDateTime {
+    type Error = DecimalTimeError;
+
+    fn try_from(t: DecimalTime) -> Result<Self, Self::Error> {
+        validate(t.year, t.day_of_year, t.decimal_day)?;
+        t.to_naive_datetime_opt().ok_or(DecimalTimeError::YearOutOfRange(t.year))
     }
 }
 
@@ -184,6 +1716,21 @@ mod tests {
     fn test_format() {
         let dec = DecimalTime::new(2025, 5, 0.5);
         let formatted = dec.format("Date => %Y-%d frac:%f");
-        assert_eq!("Date => 2025-5 frac:.5", formatted);
+        assert_eq!("Date => 2025-005 frac:.5", formatted);
+    }
+
+    #[test]
+    fn test_format_literal_between_tokens() {
+        let dec = DecimalTime::new(2025, 73, 0.25);
+        assert_eq!("2025 literal 073", dec.format("%Y literal %d"));
+    }
+
+    #[test]
+    fn test_format_single_pass() {
+        // Substituted text is never re-scanned: an escaped `%` followed by a
+        // token letter stays literal, and a token right after an escape expands once.
+        let dec = DecimalTime::new(2025, 73, 0.25);
+        assert_eq!("%d=073 %f=.25", dec.format("%%d=%d %%f=%f"));
+        assert_eq!("%073", dec.format("%%%d"));
     }
 }
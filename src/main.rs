@@ -1,19 +1,58 @@
 // src/main.rs
 
-use chrono::{DateTime, Utc, FixedOffset};
+use std::process::ExitCode;
+
 use decimal_time::DecimalTime;
 
-fn main() {
-    // Get current UTC time
-    let utc_now: DateTime<Utc> = Utc::now();
-    
-    // Convert to CET (UTC+1)
-    let cet1 = FixedOffset::east_opt(1 * 3600).expect("Valid timezone offset");
-    let cet1_now = utc_now.with_timezone(&cet1);
-    
-    // Convert to DecimalTime
-    let dec_time = DecimalTime::from_naive_datetime(cet1_now.naive_local());
-    
-    // Format using DecimalTime's custom formatter
-    println!("Right now in Decimal Time (DT): {}", dec_time.format("%Y.%d%f"));
-}
\ No newline at end of file
+const USAGE: &str = "\
+Usage:
+  decimal_time [now]                Print the current decimal time (UTC)
+  decimal_time from-iso <rfc3339>   Convert an RFC 3339 timestamp, e.g. 2025-03-14T12:00:00Z
+  decimal_time to-iso <YEAR.DDD.F>  Convert a UTC decimal time, e.g. 2025.073.5 (or 2025-073.5)";
+
+/// The form `now` and `from-iso` print, e.g. `2025.073.5`.
+const OUTPUT_FORMAT: &str = "%Y.%d%f";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        [] | ["now"] => {
+            println!("Right now in Decimal Time (DT): {}", DecimalTime::now().format(OUTPUT_FORMAT));
+            ExitCode::SUCCESS
+        }
+        ["from-iso", timestamp] => match DecimalTime::from_iso8601(timestamp) {
+            Ok(dec_time) => {
+                println!("{}", dec_time.format(OUTPUT_FORMAT));
+                ExitCode::SUCCESS
+            }
+            Err(err) => fail(&err),
+        },
+        ["to-iso", decimal] => match parse_decimal(decimal) {
+            Ok(dec_time) => {
+                println!("{}", dec_time.to_iso8601());
+                ExitCode::SUCCESS
+            }
+            Err(err) => fail(&err),
+        },
+        ["-h" | "--help" | "help"] => {
+            println!("{}", USAGE);
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Reads the output form, falling back to the canonical `FromStr` form.
+fn parse_decimal(s: &str) -> Result<DecimalTime, decimal_time::ParseError> {
+    DecimalTime::parse_from_str(s, OUTPUT_FORMAT).or_else(|_| s.parse())
+}
+
+fn fail(err: &dyn std::error::Error) -> ExitCode {
+    eprintln!("error: {}", err);
+    ExitCode::FAILURE
+}
@@ -0,0 +1,93 @@
+// src/math.rs
+//
+// `f64` rounding and trigonometry helpers that work with and without `std`.
+// `core` has no `round`/`floor`/`ceil`/`sin`/..., so `no_std` builds fall back to `libm`.
+
+#[cfg(feature = "std")]
+pub(crate) fn round(x: f64) -> f64 {
+    x.round()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn round(x: f64) -> f64 {
+    libm::round(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn floor(x: f64) -> f64 {
+    x.floor()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn floor(x: f64) -> f64 {
+    libm::floor(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn ceil(x: f64) -> f64 {
+    x.ceil()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn ceil(x: f64) -> f64 {
+    libm::ceil(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn abs(x: f64) -> f64 {
+    x.abs()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn abs(x: f64) -> f64 {
+    libm::fabs(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn sin(x: f64) -> f64 {
+    x.sin()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sin(x: f64) -> f64 {
+    libm::sin(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn cos(x: f64) -> f64 {
+    x.cos()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn cos(x: f64) -> f64 {
+    libm::cos(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    y.atan2(x)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    libm::atan2(y, x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    x.hypot(y)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn hypot(x: f64, y: f64) -> f64 {
+    libm::hypot(x, y)
+}
+
+/// Fractional places past which a day fraction has no more significant
+/// digits to round (`f64` carries at most 17).
+pub(crate) const MAX_FRACTION_DIGITS: u32 = 17;
+
+/// Returns `10^exp`.
+pub(crate) fn pow10(exp: u32) -> f64 {
+    (0..exp).fold(1.0, |acc, _| acc * 10.0)
+}
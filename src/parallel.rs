@@ -0,0 +1,28 @@
+// src/parallel.rs
+//
+// Parallel batch conversions, enabled by the `rayon` feature.
+
+use alloc::vec::Vec;
+
+use chrono::{DateTime, Utc};
+use rayon::prelude::*;
+
+use crate::DecimalTime;
+
+impl DecimalTime {
+    /// Converts every `DateTime<Utc>` in `datetimes` in parallel, keeping
+    /// the input order. Same as mapping `from_datetime_utc` over the slice.
+    pub fn par_from_datetimes_utc(datetimes: &[DateTime<Utc>]) -> Vec<DecimalTime> {
+        datetimes.par_iter().map(|dt| Self::from_datetime_utc(*dt)).collect()
+    }
+
+    /// Converts every `DecimalTime` in `times` to `DateTime<Utc>` in
+    /// parallel, keeping the input order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any date is invalid (e.g., day_of_year = 366 in a non-leap year).
+    pub fn par_to_datetimes_utc(times: &[DecimalTime]) -> Vec<DateTime<Utc>> {
+        times.par_iter().map(DecimalTime::to_datetime_utc).collect()
+    }
+}
@@ -0,0 +1,333 @@
+// src/parse.rs
+
+use alloc::format;
+use alloc::string::ToString;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::format::{Item, Items};
+use crate::{DecimalComponents, DecimalTime, ParseError};
+
+impl DecimalTime {
+    /// Parses a decimal-clock string `"H:MM:SS"` (hour 0–9, minute and
+    /// second 0–99) into a `DecimalTime` on the given day.
+    ///
+    /// The seconds may carry a fractional suffix, e.g. `"5:00:00.5"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use decimal_time::DecimalTime;
+    ///
+    /// let dec = DecimalTime::parse_clock(2025, 73, "7:50:00").unwrap();
+    /// assert_eq!(dec, DecimalTime::new(2025, 73, 0.75));
+    /// ```
+    pub fn parse_clock(year: i32, day_of_year: u32, s: &str) -> Result<DecimalTime, ParseError> {
+        let mut parts = s.split(':');
+        let (Some(hour), Some(minute), Some(second), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(ParseError::Malformed(format!("expected H:MM:SS, got {:?}", s)));
+        };
+
+        let (whole_second, frac_second) = match second.split_once('.') {
+            Some((whole, frac)) => (whole, Some(frac)),
+            None => (second, None),
+        };
+
+        let mut decimal_second = parse_digits(whole_second, "second")? as f64;
+        if let Some(frac) = frac_second {
+            if frac.is_empty() || !frac.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseError::Malformed(format!("invalid fractional second {:?}", frac)));
+            }
+            decimal_second += format!("0.{}", frac)
+                .parse::<f64>()
+                .map_err(|_| ParseError::Malformed(format!("invalid fractional second {:?}", frac)))?;
+        }
+
+        let components = DecimalComponents {
+            year,
+            day_of_year,
+            decimal_hour: parse_digits(hour, "hour")?,
+            decimal_minute: parse_digits(minute, "minute")?,
+            decimal_second,
+            hour: 0,
+            minute: 0,
+            second: 0,
+        };
+        Ok(DecimalTime::from_components(&components)?)
+    }
+}
+
+/// Parses a non-empty run of ASCII digits.
+fn parse_digits(s: &str, field: &str) -> Result<u32, ParseError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::Malformed(format!("invalid {} {:?}", field, s)));
+    }
+    s.parse()
+        .map_err(|_| ParseError::Malformed(format!("invalid {} {:?}", field, s)))
+}
+
+impl DecimalTime {
+    /// Parses `s` according to a `format`-style template, so that
+    /// `DecimalTime::parse_from_str(&dec.format(fmt), fmt)` gives back `dec`
+    /// (see below for a year run together with the next number).
+    ///
+    /// Understands the same tokens as `format` (except `%e`, which needs
+    /// an epoch):
+    /// - `%Y` => year (optionally signed); `%'Y` also skips `,` separators
+    /// - `%d` / `%j` => day_of_year (1 to 3 digits, zero-padded or not)
+    /// - `%f` / `%.Nf` => fraction of day, with or without the leading `0`
+    /// - `%0NY` / `%0Nd` / `%0Nj` => zero-padded year or day_of_year
+    /// - `%r` / `%T` => conventional `HH:MM:SS` into the day (whole seconds)
+    /// - `%R` => conventional `HH:MM` into the day (whole minutes)
+    /// - `%p` / `%.Np` => percentage of the day elapsed, e.g. `50%`
+    /// - `%c` => Gregorian `YYYY-MM-DD`, giving both year and day_of_year
+    /// - `%w` => abbreviated weekday, checked against the parsed date
+    /// - `%%` => a literal `%`
+    ///
+    /// When `%Y` is directly followed by another numeric token, as in
+    /// `%Y%d`, an unsigned year is read as at most four digits (or the `N`
+    /// of `%0NY`), so such templates only round-trip years from 1000 to
+    /// 9999 (0 to 9999 with `%04Y`); separate the year with a literal
+    /// (`%Y-%d`) for any other year.
+    ///
+    /// All other text, including unknown `%` sequences, must match exactly;
+    /// use `parse_from_format` to reject unknown sequences instead. The
+    /// year and day are required (via `%Y` and `%d`, or `%c`); a template
+    /// without a time-of-day token means midnight.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use decimal_time::DecimalTime;
+    ///
+    /// let dec = DecimalTime::parse_from_str("Year=2025 Day=100 Fraction=.5", "Year=%Y Day=%d Fraction=%f").unwrap();
+    /// assert_eq!(dec, DecimalTime::new(2025, 100, 0.5));
+    /// ```
+    pub fn parse_from_str(s: &str, fmt: &str) -> Result<DecimalTime, ParseError> {
+        parse_items(s, Items::new(fmt), false)
+    }
+
+    /// Parses `input` according to a `format` template, such as the CLI's
+    /// `"%Y.%d%f"`.
+    ///
+    /// Accepts the same tokens as `parse_from_str`, but like `try_format`
+    /// treats an unknown specifier or a trailing lone `%` in `fmt` as an
+    /// error. Every `ParseError::Malformed` names the byte position (in
+    /// `input`, or in `fmt` for a bad template) where parsing failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use decimal_time::DecimalTime;
+    ///
+    /// let dec = DecimalTime::parse_from_format("2025.073.5", "%Y.%d%f").unwrap();
+    /// assert_eq!(dec, DecimalTime::new(2025, 73, 0.5));
+    ///
+    /// let err = DecimalTime::parse_from_format("2025:073.5", "%Y.%d%f").unwrap_err();
+    /// assert_eq!(err.to_string(), "malformed input: expected '.' at position 4");
+    /// ```
+    pub fn parse_from_format(input: &str, fmt: &str) -> Result<DecimalTime, ParseError> {
+        parse_items(input, Items::new(fmt), true)
+    }
+}
+
+/// Shared body of `parse_from_str` (lenient), `parse_from_format` and
+/// `FormatSpec::parse_str` (strict): matches `s` against tokenized `items`.
+pub(crate) fn parse_items(
+    s: &str,
+    items: impl IntoIterator<Item = (usize, Item)>,
+    strict: bool,
+) -> Result<DecimalTime, ParseError> {
+    let mut year = None;
+    let mut day_of_year = None;
+    let mut decimal_day = 0.0;
+    let mut weekday = None;
+
+    let mut input = s;
+    let mut items = items.into_iter().peekable();
+    while let Some((position, item)) = items.next() {
+        let offset = s.len() - input.len();
+        match item {
+            Item::Literal(literal) => input = expect_literal(s, input, literal)?,
+            Item::Year { width } => {
+                let sign_len = usize::from(input.starts_with(['-', '+']));
+                // Like chrono, an unsigned year directly followed by digits
+                // takes at most four (or its padded width)
+                let max_digits = match items.peek() {
+                    Some((_, next)) if sign_len == 0 && next.starts_with_digit() => width.max(4),
+                    _ => usize::MAX,
+                };
+                let len = sign_len + digit_run(&input[sign_len..], max_digits);
+                year = Some(parse_field(&input[..len], "year", offset)?);
+                input = &input[len..];
+            }
+            Item::GroupedYear => {
+                let sign_len = usize::from(input.starts_with(['-', '+']));
+                let len = sign_len
+                    + input[sign_len..]
+                        .bytes()
+                        .take_while(|b| b.is_ascii_digit() || *b == b',')
+                        .count();
+                let digits = input[..len].replace(',', "");
+                year = Some(digits.parse().map_err(|_| {
+                    ParseError::Malformed(format!("invalid year {:?} at position {}", &input[..len], offset))
+                })?);
+                input = &input[len..];
+            }
+            Item::Day { width } => {
+                let len = digit_run(input, width.max(3));
+                day_of_year = Some(parse_field(&input[..len], "day_of_year", offset)?);
+                input = &input[len..];
+            }
+            Item::Fraction { .. } => (decimal_day, input) = parse_fraction(s, input)?,
+            Item::WallClock { seconds } => {
+                let clock = input.get(..if seconds { 8 } else { 5 }).unwrap_or(input);
+                decimal_day = parse_wall_clock(clock, seconds).ok_or_else(|| {
+                    let layout = if seconds { "HH:MM:SS" } else { "HH:MM" };
+                    ParseError::Malformed(format!("invalid {} {:?} at position {}", layout, clock, offset))
+                })?;
+                input = &input[clock.len()..];
+            }
+            Item::Percent { .. } => (decimal_day, input) = parse_percent(s, input)?,
+            Item::Date => {
+                let sign_len = usize::from(input.starts_with(['-', '+']));
+                let year_len = sign_len + digit_run(&input[sign_len..], usize::MAX);
+                let len = year_len + 6;
+                let date = input
+                    .get(year_len..len)
+                    .filter(|rest| rest.is_ascii() && rest.as_bytes()[0] == b'-' && rest.as_bytes()[3] == b'-')
+                    .and_then(|rest| {
+                        let month = rest[1..3].parse().ok().filter(|_| digit_run(&rest[1..3], 2) == 2)?;
+                        let day = rest[4..6].parse().ok().filter(|_| digit_run(&rest[4..6], 2) == 2)?;
+                        let year = input[..year_len].parse().ok()?;
+                        NaiveDate::from_ymd_opt(year, month, day)
+                    })
+                    .ok_or_else(|| {
+                        let text = input.get(..len).unwrap_or(input);
+                        ParseError::Malformed(format!("invalid YYYY-MM-DD {:?} at position {}", text, offset))
+                    })?;
+                year = Some(date.year());
+                day_of_year = Some(date.ordinal());
+                input = &input[len..];
+            }
+            Item::Weekday => {
+                let name = input.get(..3).unwrap_or(input);
+                weekday = Some((parse_field::<Weekday>(name, "weekday", offset)?, offset));
+                input = &input[name.len()..];
+            }
+            // `%e` needs an epoch, so it is never a token here
+            Item::DaysSince if strict => return Err(unknown_specifier('e', position)),
+            Item::Unknown(specifier) if strict => return Err(unknown_specifier(specifier, position)),
+            Item::TrailingPercent if strict => {
+                return Err(ParseError::Malformed("format string ends with a lone %".to_string()));
+            }
+            // Lenient: the `%` is a literal, and so is whatever follows it
+            Item::DaysSince => input = expect_literal(s, expect_literal(s, input, '%')?, 'e')?,
+            Item::Unknown(specifier) => input = expect_literal(s, expect_literal(s, input, '%')?, specifier)?,
+            Item::TrailingPercent => input = expect_literal(s, input, '%')?,
+        }
+    }
+
+    if !input.is_empty() {
+        return Err(ParseError::Malformed(format!(
+            "unexpected trailing input {:?} at position {}",
+            input,
+            s.len() - input.len()
+        )));
+    }
+
+    let year = year.ok_or_else(|| ParseError::Malformed("format has no %Y".to_string()))?;
+    let day_of_year =
+        day_of_year.ok_or_else(|| ParseError::Malformed("format has no %d".to_string()))?;
+    crate::validate(year, day_of_year, decimal_day)?;
+
+    let dec = DecimalTime {
+        year,
+        day_of_year,
+        decimal_day,
+    };
+    if let Some((weekday, offset)) = weekday {
+        if dec.weekday() != weekday {
+            return Err(ParseError::Malformed(format!(
+                "weekday {} does not match the date at position {}",
+                weekday, offset
+            )));
+        }
+    }
+    Ok(dec)
+}
+
+fn unknown_specifier(specifier: char, position: usize) -> ParseError {
+    ParseError::Malformed(format!("unknown format specifier %{} at position {}", specifier, position))
+}
+
+/// Strips `literal` from the front of `input`, a suffix of `s`.
+fn expect_literal<'a>(s: &str, input: &'a str, literal: char) -> Result<&'a str, ParseError> {
+    input.strip_prefix(literal).ok_or_else(|| {
+        ParseError::Malformed(format!("expected {:?} at position {}", literal, s.len() - input.len()))
+    })
+}
+
+/// Parses a `%f`-style fraction such as `.5` or `0.5`, returning the rest
+/// of `input`.
+fn parse_fraction<'a>(s: &str, input: &'a str) -> Result<(f64, &'a str), ParseError> {
+    let offset = s.len() - input.len();
+    let mut len = usize::from(input.starts_with('0'));
+    if input[len..].starts_with('.') {
+        len += 1 + digit_run(&input[len + 1..], usize::MAX);
+    }
+    let decimal_day = match &input[..len] {
+        "" | "0" | "." => 0.0,
+        frac => parse_field(frac, "decimal_day", offset)?,
+    };
+    Ok((decimal_day, &input[len..]))
+}
+
+/// Parses a `%p`-style percentage such as `50%` or `12.5%` into a day
+/// fraction, returning the rest of `input`.
+fn parse_percent<'a>(s: &str, input: &'a str) -> Result<(f64, &'a str), ParseError> {
+    let offset = s.len() - input.len();
+    let mut len = digit_run(input, usize::MAX);
+    if input[len..].starts_with('.') {
+        len += 1 + digit_run(&input[len + 1..], usize::MAX);
+    }
+    let percent: f64 = parse_field(&input[..len], "percentage", offset)?;
+    let rest = expect_literal(s, &input[len..], '%')?;
+    Ok((percent / 100.0, rest))
+}
+
+/// Parses a conventional `HH:MM:SS` time of day (or `HH:MM` without
+/// `seconds`) into a day fraction.
+fn parse_wall_clock(s: &str, seconds: bool) -> Option<f64> {
+    let bytes = s.as_bytes();
+    let len = if seconds { 8 } else { 5 };
+    if bytes.len() != len || bytes[2] != b':' || (seconds && bytes[5] != b':') {
+        return None;
+    }
+    let field = |range: core::ops::Range<usize>, max: u32| {
+        let text = &s[range];
+        (digit_run(text, 2) == 2)
+            .then(|| text.parse::<u32>().ok())
+            .flatten()
+            .filter(|v| *v <= max)
+    };
+    let mut total = field(0..2, 23)? * 3_600 + field(3..5, 59)? * 60;
+    if seconds {
+        total += field(6..8, 59)?;
+    }
+    Some(total as f64 / 86_400.0)
+}
+
+/// Returns the length of the leading run of ASCII digits, capped at `max`.
+fn digit_run(s: &str, max: usize) -> usize {
+    s.bytes().take(max).take_while(u8::is_ascii_digit).count()
+}
+
+/// Parses a field extracted at `offset`, naming it in the error.
+fn parse_field<T: core::str::FromStr>(s: &str, field: &str, offset: usize) -> Result<T, ParseError> {
+    s.parse().map_err(|_| {
+        ParseError::Malformed(format!("invalid {} {:?} at position {}", field, s, offset))
+    })
+}
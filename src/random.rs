@@ -0,0 +1,31 @@
+// src/random.rs
+//
+// Random sampling, enabled by the `rand` feature.
+
+use rand::Rng;
+
+use crate::{math, DecimalTime};
+
+impl DecimalTime {
+    /// Samples an instant uniformly from `[start, end)` by interpolating
+    /// between the two with a random `t` in [0,1).
+    ///
+    /// Returns `start` if the range is empty. Pass a seeded RNG for
+    /// reproducible output.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end < start` or either date is invalid.
+    pub fn sample_uniform<R: Rng>(rng: &mut R, start: &DecimalTime, end: &DecimalTime) -> DecimalTime {
+        let span = end.signed_duration_since(start).num_microseconds();
+        if span < 0 {
+            panic!("`end` must not be before `start`");
+        }
+
+        let t: f64 = rng.random();
+        let offset = (math::floor(span as f64 * t) as i64).min(span.saturating_sub(1)).max(0);
+        start
+            .checked_add(chrono::Duration::microseconds(offset))
+            .expect("sample lies between two valid instants")
+    }
+}
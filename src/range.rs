@@ -0,0 +1,84 @@
+// src/range.rs
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+use crate::{DecimalDuration, DecimalTime};
+
+/// A half-open span of time `[start, end)` between two `DecimalTime`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalTimeRange {
+    pub start: DecimalTime,
+    pub end: DecimalTime,
+}
+
+impl DecimalTimeRange {
+    /// Creates a new range from `start` (inclusive) to `end` (exclusive).
+    pub fn new(start: DecimalTime, end: DecimalTime) -> Self {
+        DecimalTimeRange { start, end }
+    }
+
+    /// Returns the range with `start` and `end` clamped into
+    /// `[DecimalTime::min_value(), DecimalTime::max_value()]`, so that
+    /// later conversions of either endpoint cannot fail on the year.
+    pub fn clamped_to_representable(self) -> DecimalTimeRange {
+        let (min, max) = (DecimalTime::min_value(), DecimalTime::max_value());
+        DecimalTimeRange {
+            start: self.start.clamp(min, max),
+            end: self.end.clamp(min, max),
+        }
+    }
+
+    /// Returns `true` if `start <= t < end`.
+    pub fn contains(&self, t: &DecimalTime) -> bool {
+        self.start <= *t && *t < self.end
+    }
+
+    /// Returns `true` if the two ranges share at least one instant.
+    ///
+    /// Ranges that only touch (one's `end` is the other's `start`) do not
+    /// overlap, and neither do empty ranges.
+    pub fn overlaps(&self, other: &DecimalTimeRange) -> bool {
+        self.start < other.end && other.start < self.end && self.start < self.end && other.start < other.end
+    }
+
+    /// Returns the signed duration from `start` to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either endpoint is an invalid date.
+    pub fn duration(&self) -> DecimalDuration {
+        self.end.signed_duration_since(&self.start)
+    }
+
+    /// Renders the range as `"{start} – {end} ({duration})"`, formatting
+    /// both endpoints with `DecimalTime::format(fmt)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use decimal_time::{DecimalTime, DecimalTimeRange};
+    ///
+    /// let range = DecimalTimeRange::new(
+    ///     DecimalTime::new(2025, 73, 0.5),
+    ///     DecimalTime::new(2025, 74, 0.5),
+    /// );
+    /// assert_eq!(range.format("%Y-%d%f"), "2025-073.5 – 2025-074.5 (1d)");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format(&self, fmt: &str) -> String {
+        self.format_with_separator(fmt, " – ")
+    }
+
+    /// Like `format`, but with a custom separator between the endpoints.
+    #[cfg(feature = "alloc")]
+    pub fn format_with_separator(&self, fmt: &str, separator: &str) -> String {
+        format!(
+            "{}{}{} ({})",
+            self.start.format(fmt),
+            separator,
+            self.end.format(fmt),
+            self.duration()
+        )
+    }
+}
@@ -0,0 +1,78 @@
+// src/republican.rs
+//
+// French Republican calendar names, enabled by the `republican` feature.
+
+use crate::{days_in_year, DecimalTime};
+
+const MONTHS: [&str; 12] = [
+    "Vendémiaire",
+    "Brumaire",
+    "Frimaire",
+    "Nivôse",
+    "Pluviôse",
+    "Ventôse",
+    "Germinal",
+    "Floréal",
+    "Prairial",
+    "Messidor",
+    "Thermidor",
+    "Fructidor",
+];
+
+/// Names of the ten days of a décade.
+const DAYS: [&str; 10] = [
+    "Primidi", "Duodi", "Tridi", "Quartidi", "Quintidi", "Sextidi", "Septidi", "Octidi", "Nonidi", "Décadi",
+];
+
+/// Names of the complementary days after the twelfth month; the sixth only
+/// occurs in leap years.
+const COMPLEMENTARY_DAYS: [&str; 6] = [
+    "Jour de la vertu",
+    "Jour du génie",
+    "Jour du travail",
+    "Jour de l'opinion",
+    "Jour des récompenses",
+    "Jour de la révolution",
+];
+
+impl DecimalTime {
+    /// Returns the Republican month that `day_of_year` falls in, treating
+    /// the year as twelve 30-day months from day 1, followed by the 5 (or 6
+    /// in a leap year) complementary days, which are named `"Sansculottides"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `day_of_year` is out of range for `year`.
+    pub fn republican_month(&self) -> &'static str {
+        match self.republican_index() {
+            index if index < 360 => MONTHS[index / 30],
+            _ => "Sansculottides",
+        }
+    }
+
+    /// Returns the Republican name of the day: its décade day name
+    /// (`"Primidi"` … `"Décadi"`) within one of the twelve months, or the
+    /// name of the complementary day (e.g. `"Jour de la révolution"` for
+    /// day 366 of a leap year).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `day_of_year` is out of range for `year`.
+    pub fn republican_day_name(&self) -> &'static str {
+        match self.republican_index() {
+            index if index < 360 => DAYS[index % 10],
+            index => COMPLEMENTARY_DAYS[index - 360],
+        }
+    }
+
+    /// Zero-based `day_of_year`, checked against the year's length.
+    fn republican_index(&self) -> usize {
+        if !(1..=days_in_year(self.year)).contains(&self.day_of_year) {
+            panic!(
+                "Invalid day_of_year={} for year={}",
+                self.day_of_year, self.year
+            );
+        }
+        self.day_of_year as usize - 1
+    }
+}
@@ -0,0 +1,324 @@
+// src/serde.rs
+//
+// Serde support, enabled by the `serde` feature.
+
+//! Serde representations of `DecimalTime`, `DecimalDuration` and
+//! `DecimalTimeRange`.
+//!
+//! `DecimalTime` itself serializes as a struct,
+//! `{"year": 2025, "day_of_year": 100, "decimal_day": 0.5}`. Pick a
+//! representation per field with `#[serde(with = "...")]`:
+//!
+//! - [`struct_repr`] - the struct form above
+//! - [`string`] - the compact string `"2025-100.5"` (needs `alloc`)
+//!
+//! [`chrono_as_decimal`] keeps a field's `DateTime<Utc>` type but writes it
+//! in the string form.
+//!
+//! ```
+//! use decimal_time::DecimalTime;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "decimal_time::serde::string")]
+//!     at: DecimalTime,
+//! }
+//!
+//! let event = Event { at: DecimalTime::new(2025, 100, 0.5) };
+//! assert_eq!(serde_json::to_string(&event).unwrap(), r#"{"at":"2025-100.5"}"#);
+//! ```
+//!
+//! Deserializing validates the fields like `DecimalTime::try_new`.
+//!
+//! `DecimalDuration` serializes as its whole number of microseconds, which
+//! is exact, and `DecimalTimeRange` as `{"start": ..., "end": ...}` with
+//! both endpoints in the struct form.
+//!
+//! With the `schemars` feature, all three types implement `JsonSchema`, and
+//! each string module has a `json_schema` function for
+//! `#[schemars(schema_with = "...")]`.
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{DecimalDuration, DecimalTime, DecimalTimeRange};
+
+/// Same as `serde::struct_repr`.
+impl Serialize for DecimalTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct_repr::serialize(self, serializer)
+    }
+}
+
+/// Same as `serde::struct_repr`.
+impl<'de> Deserialize<'de> for DecimalTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct_repr::deserialize(deserializer)
+    }
+}
+
+/// Same as `serde::struct_repr`.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for DecimalTime {
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "DecimalTime".into()
+    }
+
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        "decimal_time::DecimalTime".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        struct_repr::json_schema(generator)
+    }
+}
+
+/// A whole number of microseconds, e.g. `43200000000` for half a day.
+impl Serialize for DecimalDuration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.num_microseconds())
+    }
+}
+
+/// Reads the microsecond count written by `Serialize`.
+impl<'de> Deserialize<'de> for DecimalDuration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i64::deserialize(deserializer).map(DecimalDuration::from_microseconds)
+    }
+}
+
+/// The microsecond count, same as `Serialize`.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for DecimalDuration {
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "DecimalDuration".into()
+    }
+
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        "decimal_time::DecimalDuration".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "integer",
+            "format": "int64",
+            "description": "A signed span of time in microseconds; a decimal day is 86400000000.",
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "DecimalTimeRange", crate = "::serde")]
+struct RangeRepr {
+    start: DecimalTime,
+    end: DecimalTime,
+}
+
+/// A `{start, end}` struct, each endpoint in the `struct_repr` form.
+impl Serialize for DecimalTimeRange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RangeRepr { start: self.start, end: self.end }.serialize(serializer)
+    }
+}
+
+/// Reads the struct written by `Serialize`, validating each endpoint like
+/// `DecimalTime::try_new`. As with `DecimalTimeRange::new`, `start` may be
+/// after `end`.
+impl<'de> Deserialize<'de> for DecimalTimeRange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = RangeRepr::deserialize(deserializer)?;
+        Ok(DecimalTimeRange::new(repr.start, repr.end))
+    }
+}
+
+/// The `{start, end}` struct, referencing `DecimalTime`'s schema.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for DecimalTimeRange {
+    fn schema_name() -> alloc::borrow::Cow<'static, str> {
+        "DecimalTimeRange".into()
+    }
+
+    fn schema_id() -> alloc::borrow::Cow<'static, str> {
+        "decimal_time::DecimalTimeRange".into()
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        let endpoint = generator.subschema_for::<DecimalTime>();
+        schemars::json_schema!({
+            "type": "object",
+            "description": "A half-open span of time [start, end) between two decimal times.",
+            "properties": {
+                "start": endpoint,
+                "end": endpoint,
+            },
+            "required": ["start", "end"],
+        })
+    }
+}
+
+/// (De)serializes a `DecimalTime` as a struct with `year`, `day_of_year`
+/// and `decimal_day` fields.
+pub mod struct_repr {
+    use ::serde::de::Error;
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::DecimalTime;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "DecimalTime", crate = "::serde")]
+    struct Repr {
+        year: i32,
+        day_of_year: u32,
+        decimal_day: f64,
+    }
+
+    /// Serializes `time` as a `DecimalTime { year, day_of_year, decimal_day }` struct.
+    pub fn serialize<S: Serializer>(time: &DecimalTime, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr {
+            year: time.year,
+            day_of_year: time.day_of_year,
+            decimal_day: time.decimal_day,
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes the struct written by `serialize`, rejecting fields
+    /// that `DecimalTime::try_new` would.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DecimalTime, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        DecimalTime::try_new(repr.year, repr.day_of_year, repr.decimal_day).map_err(D::Error::custom)
+    }
+
+    /// The JSON schema of the struct form, with each field's range.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "object",
+            "description": "A decimal time: a day of a Gregorian year plus the fraction of that day elapsed.",
+            "properties": {
+                "year": {
+                    "type": "integer",
+                    "minimum": DecimalTime::MIN_YEAR,
+                    "maximum": DecimalTime::MAX_YEAR,
+                },
+                "day_of_year": {
+                    "type": "integer",
+                    "description": "1-based; 366 only in leap years.",
+                    "minimum": 1,
+                    "maximum": 366,
+                },
+                "decimal_day": {
+                    "type": "number",
+                    "description": "Fraction of the day: 0 is midnight, 0.5 is noon.",
+                    "minimum": 0,
+                    "exclusiveMaximum": 1,
+                },
+            },
+            "required": ["year", "day_of_year", "decimal_day"],
+        })
+    }
+}
+
+/// (De)serializes a `DecimalTime` as the compact string `YEAR-DDD.F`,
+/// e.g. `"2025-100.5"`, with the full fraction (`format("%Y-%d%f")`).
+///
+/// This is the canonical `Display` form; reads anything
+/// `str::parse::<DecimalTime>` accepts.
+#[cfg(feature = "alloc")]
+pub mod string {
+    use core::fmt;
+
+    use ::serde::de::{self, Visitor};
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::DecimalTime;
+
+    /// Serializes `time` as a string without allocating.
+    pub fn serialize<S: Serializer>(time: &DecimalTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&time.format_lazy("%Y-%d%f"))
+    }
+
+    /// Deserializes a string with `str::parse::<DecimalTime>`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DecimalTime, D::Error> {
+        deserializer.deserialize_str(StringVisitor)
+    }
+
+    /// The JSON schema of the string form.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "description": "A decimal time `YEAR-DDD.F`: the year, the zero-padded day of the year (1-366) and the fraction of the day elapsed, e.g. \"2025-100.5\" for noon on April 10th, 2025. Midnight omits the fraction.",
+            "pattern": STRING_PATTERN,
+        })
+    }
+
+    /// Matches what `deserialize` accepts (leaving range checks to it).
+    #[cfg(feature = "schemars")]
+    const STRING_PATTERN: &str = r"^[+-]?[0-9]+-[0-9]{1,3}(0?\.[0-9]*)?$";
+
+    struct StringVisitor;
+
+    impl Visitor<'_> for StringVisitor {
+        type Value = DecimalTime;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a decimal time string such as \"2025-100.5\"")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<DecimalTime, E> {
+            s.parse().map_err(E::custom)
+        }
+    }
+}
+
+/// (De)serializes a `chrono::DateTime<Utc>` field as a decimal time
+/// string, in the same form as [`string`], without changing its type.
+///
+/// The value passes through `DecimalTime::try_from`, so it keeps
+/// microsecond precision.
+///
+/// ```
+/// use chrono::{DateTime, TimeZone, Utc};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "decimal_time::serde::chrono_as_decimal")]
+///     at: DateTime<Utc>,
+/// }
+///
+/// let event = Event { at: Utc.with_ymd_and_hms(2025, 3, 14, 18, 0, 0).unwrap() };
+/// let json = serde_json::to_string(&event).unwrap();
+/// assert_eq!(json, r#"{"at":"2025-073.75"}"#);
+/// assert_eq!(serde_json::from_str::<Event>(&json).unwrap().at, event.at);
+/// ```
+#[cfg(feature = "alloc")]
+pub mod chrono_as_decimal {
+    use ::serde::de::Error;
+    use ::serde::{Deserializer, Serializer};
+    use chrono::{DateTime, NaiveDateTime, Utc};
+
+    use crate::DecimalTime;
+
+    /// Serializes `dt` as a decimal time string, failing on a leap second
+    /// at the end of a day, which has no fraction in [0,1).
+    pub fn serialize<S: Serializer>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+        let time = DecimalTime::try_from(*dt).map_err(::serde::ser::Error::custom)?;
+        super::string::serialize(&time, serializer)
+    }
+
+    /// Deserializes a decimal time string back into a `DateTime<Utc>`.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+        let time = super::string::deserialize(deserializer)?;
+        NaiveDateTime::try_from(time)
+            .map(|ndt| ndt.and_utc())
+            .map_err(D::Error::custom)
+    }
+
+    /// The JSON schema of the string form, same as `string::json_schema`.
+    #[cfg(feature = "schemars")]
+    pub fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        super::string::json_schema(generator)
+    }
+}
@@ -0,0 +1,90 @@
+// src/spec.rs
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::str::FromStr;
+
+use crate::format::{Item, Items, WriteError};
+use crate::{DecimalTime, FormatError, ParseError};
+
+/// A format string compiled once into its tokens, for formatting or
+/// parsing many values with the same layout (like chrono's `StrftimeItems`).
+///
+/// Understands the same tokens as `DecimalTime::format`, except `%e`.
+///
+/// # Example
+///
+/// ```
+/// use decimal_time::{DecimalTime, FormatSpec};
+///
+/// let spec = FormatSpec::parse("%Y.%03j%.3f").unwrap();
+/// let dec = DecimalTime::new(2025, 73, 0.5);
+/// assert_eq!(spec.format(&dec), "2025.073.500");
+/// assert_eq!(spec.parse_str("2025.073.500"), Ok(dec));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatSpec {
+    items: Vec<(usize, Item)>,
+}
+
+impl FormatSpec {
+    /// Compiles `fmt`, rejecting unknown specifiers and a trailing lone `%`
+    /// like `try_format`. `%e` is rejected too, as there is no epoch to
+    /// measure from.
+    pub fn parse(fmt: &str) -> Result<FormatSpec, FormatError> {
+        let items: Vec<_> = Items::new(fmt).collect();
+        for &(position, item) in &items {
+            match item {
+                Item::Unknown(specifier) => return Err(FormatError::UnknownSpecifier { specifier, position }),
+                Item::DaysSince => return Err(FormatError::UnknownSpecifier { specifier: 'e', position }),
+                Item::TrailingPercent => return Err(FormatError::TrailingPercent),
+                _ => {}
+            }
+        }
+        Ok(FormatSpec { items })
+    }
+
+    /// Formats `time`, like `time.format(fmt)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spec contains `%w` or `%c` and the date is invalid.
+    pub fn format(&self, time: &DecimalTime) -> String {
+        let mut output = String::with_capacity(self.items.len());
+        self.format_into(&mut output, time)
+            .expect("writing to a String cannot fail");
+        output
+    }
+
+    /// Like `format`, but writes straight into `w`. Fails only if `w` does.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spec contains `%w` or `%c` and the date is invalid.
+    pub fn format_into<W: Write + ?Sized>(&self, w: &mut W, time: &DecimalTime) -> core::fmt::Result {
+        for &(position, item) in &self.items {
+            time.write_item(w, position, item, None, false, ',')
+                .map_err(|err| match err {
+                    WriteError::Fmt(err) => err,
+                    WriteError::Format(_) => unreachable!("compiled specs hold no invalid tokens"),
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Parses `s` laid out by this spec, like
+    /// `DecimalTime::parse_from_format(s, fmt)`.
+    pub fn parse_str(&self, s: &str) -> Result<DecimalTime, ParseError> {
+        crate::parse::parse_items(s, self.items.iter().copied(), true)
+    }
+}
+
+/// Same as `FormatSpec::parse`.
+impl FromStr for FormatSpec {
+    type Err = FormatError;
+
+    fn from_str(fmt: &str) -> Result<Self, Self::Err> {
+        FormatSpec::parse(fmt)
+    }
+}
@@ -0,0 +1,53 @@
+// src/time_compat.rs
+//
+// Conversions to and from the `time` crate, enabled by the `time` feature.
+
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::{math, DecimalTime, NANOS_PER_DAY};
+
+impl From<PrimitiveDateTime> for DecimalTime {
+    /// Converts a `time::PrimitiveDateTime`, mirroring `from_naive_datetime`.
+    fn from(dt: PrimitiveDateTime) -> Self {
+        let t = dt.time();
+        let nanos_since_midnight = (t.hour() as u64 * 3_600 + t.minute() as u64 * 60 + t.second() as u64)
+            * 1_000_000_000
+            + t.nanosecond() as u64;
+
+        DecimalTime::new(
+            dt.year(),
+            dt.ordinal() as u32,
+            nanos_since_midnight as f64 / NANOS_PER_DAY as f64,
+        )
+    }
+}
+
+impl From<OffsetDateTime> for DecimalTime {
+    /// Converts a `time::OffsetDateTime` after normalizing it to UTC.
+    fn from(dt: OffsetDateTime) -> Self {
+        let utc = dt.to_offset(UtcOffset::UTC);
+        PrimitiveDateTime::new(utc.date(), utc.time()).into()
+    }
+}
+
+impl DecimalTime {
+    /// Converts `DecimalTime` into a `time::OffsetDateTime`, assuming UTC.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date is invalid (e.g., day_of_year = 366 in a non-leap year)
+    /// or out of the `time` crate's range.
+    pub fn to_offset_datetime(&self) -> OffsetDateTime {
+        let date = Date::from_ordinal_date(self.year, self.day_of_year as u16)
+            .unwrap_or_else(|_| {
+                panic!(
+                    "Invalid day_of_year={} for year={}",
+                    self.day_of_year, self.year
+                )
+            });
+
+        let nanos = math::round(self.decimal_day * NANOS_PER_DAY as f64) as i64;
+        (PrimitiveDateTime::new(date, Time::MIDNIGHT) + time::Duration::nanoseconds(nanos))
+            .assume_utc()
+    }
+}
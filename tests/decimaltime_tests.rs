@@ -1,4 +1,4 @@
-use decimal_time::DecimalTime;
+use decimal_time::{DecimalDate, DecimalDuration, DecimalTime, DecimalTimeError, FormatError, FormatSpec, ParseError, RoundingMode};
 use chrono::{TimeZone, NaiveDate, Utc};
 use chrono::{Datelike, Timelike};
 
@@ -44,11 +44,11 @@ fn test_leap_year_valid_366() {
     let _ = DecimalTime::new(2024, 366, 0.5); // 2024 is a leap year, should be fine
 }
 
-// #[test]
-// #[should_panic]
-// fn test_non_leap_year_day_366() {
-//     DecimalTime::new(2025, 366, 0.5); // 2025 is NOT a leap year
-// }
+#[test]
+#[should_panic(expected = "`day_of_year` must be in [1..=365] for year 2025")]
+fn test_non_leap_year_day_366() {
+    DecimalTime::new(2025, 366, 0.5); // 2025 is NOT a leap year
+}
 
 /// ✅ Test conversion from `NaiveDateTime`
 #[test]
@@ -163,3 +163,2088 @@ fn test_seconds_accuracy() {
 
     assert!((diff - expected_diff).abs() < f64::EPSILON, "1 second shift failed");
 }
+
+
+/// ✅ Test `next_available_in_window` inside the window returns self
+#[test]
+fn test_next_available_in_window_inside() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!(dec.next_available_in_window(0.375, 0.75), dec);
+}
+
+/// ✅ Test `next_available_in_window` before the window snaps to today's start
+#[test]
+fn test_next_available_in_window_before() {
+    let dec = DecimalTime::new(2025, 73, 0.1);
+    let next = dec.next_available_in_window(0.375, 0.75);
+
+    assert_eq!(next, DecimalTime::new(2025, 73, 0.375));
+}
+
+/// ✅ Test `next_available_in_window` past the window carries to tomorrow's start
+#[test]
+fn test_next_available_in_window_after() {
+    let dec = DecimalTime::new(2025, 73, 0.8);
+    let next = dec.next_available_in_window(0.375, 0.75);
+
+    assert_eq!(next, DecimalTime::new(2025, 74, 0.375));
+}
+
+/// ✅ Test `next_available_in_window` carrying across a year boundary
+#[test]
+fn test_next_available_in_window_year_boundary() {
+    let dec = DecimalTime::new(2024, 366, 0.9);
+    let next = dec.next_available_in_window(0.375, 0.75);
+
+    assert_eq!(next, DecimalTime::new(2025, 1, 0.375));
+}
+
+/// ✅ Test `approx_eq` tolerates tiny fraction drift but not date differences
+#[test]
+fn test_approx_eq() {
+    let a = DecimalTime::new(2025, 73, 0.5);
+    let b = DecimalTime::new(2025, 73, 0.5 + 1e-12);
+    let other_day = DecimalTime::new(2025, 74, 0.5);
+
+    assert!(a.approx_eq(&b, 1e-9));
+    assert!(!a.approx_eq(&DecimalTime::new(2025, 73, 0.51), 1e-9));
+    assert!(!a.approx_eq(&other_day, 1e-9));
+}
+
+/// ✅ Test `components` breaks noon into decimal and conventional units
+#[test]
+fn test_components_noon() {
+    let c = DecimalTime::new(2025, 73, 0.5).components();
+
+    assert_eq!(c.decimal_hour, 5);
+    assert_eq!(c.decimal_minute, 0);
+    assert_eq!(c.decimal_second, 0.0);
+    assert_eq!((c.hour, c.minute, c.second), (12, 0, 0));
+}
+
+/// ✅ Test round trip through `components` / `from_components`
+#[test]
+fn test_components_round_trip() {
+    let dec = DecimalTime::new(2025, 73, 0.123456);
+    let back = DecimalTime::from_components(&dec.components()).unwrap();
+
+    assert!(back.approx_eq(&dec, 1e-12));
+}
+
+/// ✅ Test editing a component and rebuilding
+#[test]
+fn test_from_components_edited() {
+    let mut c = DecimalTime::new(2025, 73, 0.5).components();
+    c.decimal_hour = 7;
+    c.decimal_minute = 50;
+
+    let dec = DecimalTime::from_components(&c).unwrap();
+    assert!(dec.approx_eq(&DecimalTime::new(2025, 73, 0.75), 1e-12));
+}
+
+/// ❌ Test `from_components` rejects out-of-range decimal units
+#[test]
+fn test_from_components_invalid() {
+    let mut c = DecimalTime::new(2025, 73, 0.5).components();
+    c.decimal_minute = 100;
+    assert!(matches!(
+        DecimalTime::from_components(&c),
+        Err(DecimalTimeError::ComponentOutOfRange { component: "minute", .. })
+    ));
+
+    let mut c = DecimalTime::new(2025, 73, 0.5).components();
+    c.day_of_year = 366; // 2025 is not a leap year
+    assert!(matches!(
+        DecimalTime::from_components(&c),
+        Err(DecimalTimeError::DayOfYearOutOfRange { .. })
+    ));
+}
+
+/// ✅ Test `checked_add` / `checked_sub` carry across the year boundary
+#[test]
+fn test_checked_add_sub() {
+    let dec = DecimalTime::new(2024, 366, 0.75);
+    let later = dec.checked_add(chrono::Duration::hours(12)).unwrap();
+    assert_eq!(later, DecimalTime::new(2025, 1, 0.25));
+
+    let back = later.checked_sub(chrono::Duration::hours(12)).unwrap();
+    assert_eq!(back, dec);
+
+    let invalid = DecimalTime { year: 2025, day_of_year: 366, decimal_day: 0.5 };
+    assert_eq!(invalid.checked_add(chrono::Duration::hours(1)), None);
+}
+
+/// ✅ Test adding 5000 decimal minutes rolls the day over by 5
+#[test]
+fn test_add_decimal_minutes() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    let later = dec.add_decimal_minutes(5000);
+
+    assert_eq!(later.day_of_year, 78);
+    assert!(later.approx_eq(&DecimalTime::new(2025, 78, 0.5), 1e-12));
+
+    let earlier = dec.add_decimal_minutes(-250);
+    assert!(earlier.approx_eq(&DecimalTime::new(2025, 73, 0.25), 1e-12));
+}
+
+/// ✅ Test `add_beats` wraps across the year boundary
+#[test]
+fn test_add_beats() {
+    let dec = DecimalTime::new(2025, 365, 0.9);
+    let later = dec.add_beats(200);
+
+    assert!(later.approx_eq(&DecimalTime::new(2026, 1, 0.1), 1e-12));
+}
+
+/// ✅ Test a year yields ten decimal-hour ticks per day
+#[test]
+fn test_decimal_hour_ticks_for_year() {
+    for year in [2024, 2025] {
+        let count = DecimalTime::decimal_hour_ticks_for_year(year).count();
+        assert_eq!(count, decimal_time::days_in_year(year) as usize * 10);
+    }
+
+    let mut ticks = DecimalTime::decimal_hour_ticks_for_year(2025);
+    assert_eq!(ticks.nth(11), Some(DecimalTime::new(2025, 2, 0.1)));
+    assert_eq!(ticks.last(), Some(DecimalTime::new(2025, 365, 0.9)));
+}
+
+/// ✅ Test `fraction_of_year` uses the real year length
+#[test]
+fn test_fraction_of_year() {
+    assert_eq!(DecimalTime::new(2025, 1, 0.0).fraction_of_year(), 0.0);
+    assert!((DecimalTime::new(2025, 183, 0.5).fraction_of_year() - 0.5).abs() < 1e-12);
+    assert!((DecimalTime::new(2024, 184, 0.0).fraction_of_year() - 0.5).abs() < 1e-12);
+}
+
+/// ✅ Test `from_fraction_of_year` inverts `fraction_of_year`
+#[test]
+fn test_from_fraction_of_year() {
+    let dec = DecimalTime::new(2024, 300, 0.125);
+    let back = DecimalTime::from_fraction_of_year(2024, dec.fraction_of_year()).unwrap();
+    assert!(back.approx_eq(&dec, 1e-9));
+
+    assert_eq!(DecimalTime::from_fraction_of_year(2025, 0.5), Some(DecimalTime::new(2025, 183, 0.5)));
+    assert_eq!(DecimalTime::from_fraction_of_year(2025, 1.0), None);
+}
+
+/// ✅ Test `checked_round` in each mode
+#[test]
+fn test_checked_round() {
+    let dec = DecimalTime::new(2025, 73, 0.123456);
+
+    assert_eq!(dec.checked_round(3, RoundingMode::Nearest), Some(DecimalTime::new(2025, 73, 0.123)));
+    assert_eq!(dec.checked_round(3, RoundingMode::Up), Some(DecimalTime::new(2025, 73, 0.124)));
+    assert_eq!(dec.checked_round(3, RoundingMode::Down), Some(DecimalTime::new(2025, 73, 0.123)));
+}
+
+/// ✅ Test rounding to more places than `f64` holds leaves the value unchanged
+#[test]
+fn test_checked_round_large_digits() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!(dec.checked_round(400, RoundingMode::Nearest), Some(dec));
+    assert_eq!(dec.checked_round(u32::MAX, RoundingMode::Up), Some(dec));
+
+    let midnight = DecimalTime::new(2025, 73, 0.0);
+    assert_eq!(midnight.checked_round(400, RoundingMode::Down), Some(midnight));
+}
+
+/// ✅ Test rounding up at the end of a year carries into the next year
+#[test]
+fn test_round_to_places_carries() {
+    let dec = DecimalTime::new(2024, 366, 0.9996);
+    assert_eq!(dec.round_to_places(3, RoundingMode::Nearest), DecimalTime::new(2025, 1, 0.0));
+}
+
+/// ❌ Test rounding up at the representable edge returns `None`
+#[test]
+fn test_checked_round_at_max() {
+    let max = DecimalTime::max_value();
+    assert_eq!(max.checked_round(3, RoundingMode::Up), None);
+    assert_eq!(max.checked_round(3, RoundingMode::Down).unwrap().decimal_day, 0.999);
+}
+
+/// ✅ Test `min_value` / `max_value` convert to chrono's limits
+#[test]
+fn test_min_max_value() {
+    assert_eq!(DecimalTime::min_value().to_naive_datetime(), chrono::NaiveDateTime::MIN);
+    assert_eq!(DecimalTime::max_value().to_naive_datetime().date(), NaiveDate::MAX);
+}
+
+/// ✅ Test chronological ordering across fields
+#[test]
+fn test_ordering() {
+    let a = DecimalTime::new(2024, 366, 0.9);
+    let b = DecimalTime::new(2025, 1, 0.1);
+    let c = DecimalTime::new(2025, 1, 0.2);
+
+    assert!(a < b && b < c);
+    assert_eq!(std::cmp::max(a, c), c);
+
+    let mut times = vec![c, a, b];
+    times.sort();
+    assert_eq!(times, vec![a, b, c]);
+}
+
+/// ✅ Test `clamp` follows `Ord::clamp` semantics
+#[test]
+fn test_clamp() {
+    let min = DecimalTime::new(2025, 73, 0.375);
+    let max = DecimalTime::new(2025, 73, 0.75);
+
+    assert_eq!(DecimalTime::new(2025, 73, 0.5).clamp(min, max), DecimalTime::new(2025, 73, 0.5));
+    assert_eq!(DecimalTime::new(2025, 73, 0.1).clamp(min, max), min);
+    assert_eq!(DecimalTime::new(2025, 74, 0.1).clamp(min, max), max);
+}
+
+/// ❌ Test `clamp` panics when `min > max`
+#[test]
+#[should_panic]
+fn test_clamp_inverted_bounds() {
+    let min = DecimalTime::new(2025, 73, 0.75);
+    let max = DecimalTime::new(2025, 73, 0.375);
+    let _ = DecimalTime::new(2025, 73, 0.5).clamp(min, max);
+}
+
+/// ✅ Test `nanos_of_day` / `from_nanos_of_day` at noon
+#[test]
+fn test_nanos_of_day_noon() {
+    let dec = DecimalTime::from_nanos_of_day(2025, 73, 43_200_000_000_000).unwrap();
+
+    assert_eq!(dec.decimal_day, 0.5);
+    assert_eq!(dec.nanos_of_day(), 43_200_000_000_000);
+}
+
+/// ✅ Test `nanos_of_day` round-trips the last nanosecond of the day
+#[test]
+fn test_nanos_of_day_just_below_max() {
+    let dec = DecimalTime::from_nanos_of_day(2025, 73, 86_399_999_999_999).unwrap();
+
+    assert!(dec.decimal_day < 1.0);
+    assert_eq!(dec.nanos_of_day(), 86_399_999_999_999);
+}
+
+/// ❌ Test `from_nanos_of_day` rejects a full day
+#[test]
+fn test_from_nanos_of_day_invalid() {
+    assert!(DecimalTime::from_nanos_of_day(2025, 73, 86_400_000_000_000).is_err());
+}
+
+/// ✅ Test décade grouping of days
+#[test]
+fn test_decade_of_year() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!((dec.decade_of_year(), dec.day_of_decade()), (8, 3));
+
+    let first = DecimalTime::new(2025, 1, 0.0);
+    assert_eq!((first.decade_of_year(), first.day_of_decade()), (1, 1));
+
+    let tenth = DecimalTime::new(2025, 10, 0.0);
+    assert_eq!((tenth.decade_of_year(), tenth.day_of_decade()), (1, 10));
+}
+
+/// ✅ Test the partial décade at year-end
+#[test]
+fn test_decade_of_year_end() {
+    let last = DecimalTime::new(2025, 365, 0.0);
+    assert_eq!((last.decade_of_year(), last.day_of_decade()), (37, 5));
+
+    let leap_last = DecimalTime::new(2024, 366, 0.0);
+    assert_eq!((leap_last.decade_of_year(), leap_last.day_of_decade()), (37, 6));
+}
+
+/// ✅ Test the same calendar day in a different year is an anniversary
+#[test]
+fn test_is_anniversary_of_same_day() {
+    let original = DecimalTime::new(2020, 100, 0.5); // April 9th in a leap year
+    let later = DecimalTime::new(2025, 99, 0.55); // April 9th in a common year
+
+    assert!(later.is_anniversary_of(&original, DecimalDuration::from_decimal_days(0.1)));
+    assert!(!later.is_anniversary_of(&original, DecimalDuration::from_decimal_days(0.01)));
+    assert!(!DecimalTime::new(2025, 100, 0.5).is_anniversary_of(&original, DecimalDuration::ZERO));
+}
+
+/// ✅ Test a tolerance can reach across New Year
+#[test]
+fn test_is_anniversary_of_across_new_year() {
+    let original = DecimalTime::new(2020, 1, 0.05);
+    let later = DecimalTime::new(2024, 366, 0.95);
+
+    assert!(later.is_anniversary_of(&original, DecimalDuration::from_decimal_days(0.1)));
+}
+
+/// ✅ Test Feb 29 matches Feb 28 in non-leap years
+#[test]
+fn test_is_anniversary_of_leap_day() {
+    let leap_day = DecimalTime::new(2024, 60, 0.5); // Feb 29th
+
+    assert!(DecimalTime::new(2025, 59, 0.5).is_anniversary_of(&leap_day, DecimalDuration::ZERO)); // Feb 28th
+    assert!(!DecimalTime::new(2025, 60, 0.5).is_anniversary_of(&leap_day, DecimalDuration::ZERO)); // Mar 1st
+    assert!(DecimalTime::new(2028, 60, 0.5).is_anniversary_of(&leap_day, DecimalDuration::ZERO)); // Feb 29th
+    assert!(!DecimalTime::new(2028, 59, 0.5).is_anniversary_of(&leap_day, DecimalDuration::ZERO)); // Feb 28th
+}
+
+/// ✅ Test the checked `with_*` setters
+#[test]
+fn test_with_setters() {
+    let dec = DecimalTime::new(2024, 366, 0.5);
+
+    assert_eq!(dec.with_decimal_day(0.25), Ok(DecimalTime::new(2024, 366, 0.25)));
+    assert_eq!(dec.with_day_of_year(1), Ok(DecimalTime::new(2024, 1, 0.5)));
+    assert_eq!(dec.with_year(2028), Ok(DecimalTime::new(2028, 366, 0.5)));
+}
+
+/// ❌ Test the `with_*` setters reject invalid values
+#[test]
+fn test_with_setters_invalid() {
+    let dec = DecimalTime::new(2024, 366, 0.5);
+
+    assert_eq!(dec.with_decimal_day(2.0), Err(DecimalTimeError::DecimalDayOutOfRange(2.0)));
+    assert!(dec.with_day_of_year(0).is_err());
+    assert_eq!(
+        dec.with_year(2025),
+        Err(DecimalTimeError::DayOfYearOutOfRange { year: 2025, day_of_year: 366 })
+    );
+}
+
+/// ✅ Test `to_naive_datetime_saturating` clamps day 366 of a non-leap year
+#[test]
+fn test_to_naive_datetime_saturating_non_leap_366() {
+    let invalid = DecimalTime { year: 2025, day_of_year: 366, decimal_day: 0.5 };
+    let dt = invalid.to_naive_datetime_saturating();
+
+    assert_eq!(dt, NaiveDate::from_ymd_opt(2025, 12, 31).unwrap().and_hms_opt(12, 0, 0).unwrap());
+}
+
+/// ✅ Test `to_naive_datetime_saturating` clamps out-of-range years and fractions
+#[test]
+fn test_to_naive_datetime_saturating_out_of_range() {
+    let too_late = DecimalTime { year: 300_000, day_of_year: 1, decimal_day: 0.0 };
+    let too_early = DecimalTime { year: -300_000, day_of_year: 1, decimal_day: 0.0 };
+    let overfull = DecimalTime { year: 2025, day_of_year: 73, decimal_day: 1.5 };
+
+    assert_eq!(too_late.to_naive_datetime_saturating(), chrono::NaiveDateTime::MAX);
+    assert_eq!(too_early.to_naive_datetime_saturating(), chrono::NaiveDateTime::MIN);
+    assert_eq!(overfull.to_naive_datetime_saturating().date(), NaiveDate::from_ymd_opt(2025, 3, 14).unwrap());
+}
+
+/// ✅ Test parsing a decimal-clock string
+#[test]
+fn test_parse_clock() {
+    let dec = DecimalTime::parse_clock(2025, 73, "5:00:00").unwrap();
+    assert_eq!(dec, DecimalTime::new(2025, 73, 0.5));
+
+    let dec = DecimalTime::parse_clock(2025, 73, "1:23:45").unwrap();
+    assert!(dec.approx_eq(&DecimalTime::new(2025, 73, 0.12345), 1e-12));
+}
+
+/// ✅ Test parsing a decimal-clock string with fractional seconds
+#[test]
+fn test_parse_clock_fractional_seconds() {
+    let dec = DecimalTime::parse_clock(2025, 73, "5:00:00.5").unwrap();
+    assert!(dec.approx_eq(&DecimalTime::new(2025, 73, 0.500005), 1e-12));
+}
+
+/// ❌ Test `parse_clock` rejects malformed and out-of-range input
+#[test]
+fn test_parse_clock_invalid() {
+    assert!(matches!(
+        DecimalTime::parse_clock(2025, 73, "5:100:00"),
+        Err(ParseError::OutOfRange(DecimalTimeError::ComponentOutOfRange { component: "minute", .. }))
+    ));
+    assert!(matches!(DecimalTime::parse_clock(2025, 73, "10:00:00"), Err(ParseError::OutOfRange(_))));
+    assert!(matches!(DecimalTime::parse_clock(2025, 73, "5:00"), Err(ParseError::Malformed(_))));
+    assert!(matches!(DecimalTime::parse_clock(2025, 73, "5:0a:00"), Err(ParseError::Malformed(_))));
+    assert!(matches!(DecimalTime::parse_clock(2025, 366, "5:00:00"), Err(ParseError::OutOfRange(_))));
+}
+
+/// ✅ Test `microseconds_into_day` / `from_microseconds_into_day` invert each other on every second
+#[test]
+fn test_microseconds_into_day_round_trip() {
+    for second in 0..86_400u64 {
+        let micros = second * 1_000_000;
+        let dec = DecimalTime::from_microseconds_into_day(2025, 73, micros).unwrap();
+        assert_eq!(dec.microseconds_into_day(), micros);
+    }
+
+    let last = DecimalTime::from_microseconds_into_day(2025, 73, 86_399_999_999).unwrap();
+    assert_eq!(last.microseconds_into_day(), 86_399_999_999);
+}
+
+/// ❌ Test `from_microseconds_into_day` rejects a full day and invalid dates
+#[test]
+fn test_from_microseconds_into_day_invalid() {
+    assert_eq!(DecimalTime::from_microseconds_into_day(2025, 73, 86_400_000_000), None);
+    assert_eq!(DecimalTime::from_microseconds_into_day(2025, 366, 0), None);
+}
+
+/// ✅ Test `precision_loss_report` matches the actual loss after rounding
+#[test]
+fn test_precision_loss_report() {
+    let dec = DecimalTime::new(2025, 73, 0.123456);
+
+    for digits in [1, 3, 5] {
+        let rounded = dec.round_to_places(digits, RoundingMode::Nearest);
+        let actual = (rounded.decimal_day - dec.decimal_day).abs();
+        let reported = dec.precision_loss_report(digits);
+
+        assert!((reported - actual).abs() < 1e-15, "digits={}", digits);
+        assert!(reported <= 0.5 * 10f64.powi(-(digits as i32)));
+    }
+}
+
+/// ✅ Test `precision_loss_report` with more digits than `f64` holds reports no loss
+#[test]
+fn test_precision_loss_report_large_digits() {
+    let dec = DecimalTime::new(2025, 73, 0.123456);
+    assert_eq!(dec.precision_loss_report(400), 0.0);
+    assert_eq!(dec.precision_loss_report(u32::MAX), 0.0);
+}
+
+/// ✅ Test `precision_loss_report` accounts for a carry into the next day
+#[test]
+fn test_precision_loss_report_carry() {
+    let dec = DecimalTime::new(2025, 73, 0.9996);
+    let rounded = dec.round_to_places(3, RoundingMode::Nearest);
+
+    assert_eq!(rounded, DecimalTime::new(2025, 74, 0.0));
+    assert!((dec.precision_loss_report(3) - 0.0004).abs() < 1e-12);
+    assert_eq!(DecimalTime::new(2025, 73, 0.5).precision_loss_report(1), 0.0);
+}
+
+/// ✅ Test `MIN` / `MAX` match chrono's `NaiveDate` range
+#[test]
+fn test_min_max_constants() {
+    const EARLIEST: DecimalTime = DecimalTime::MIN;
+
+    assert_eq!(EARLIEST.to_naive_datetime(), NaiveDate::MIN.and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(
+        DecimalTime::MAX.to_naive_datetime(),
+        NaiveDate::MAX.and_hms_micro_opt(23, 59, 59, 999_999).unwrap()
+    );
+}
+
+/// ✅ Test `MAX` works as a starting accumulator for finding the minimum
+#[test]
+fn test_max_as_accumulator() {
+    let times = [
+        DecimalTime::new(2025, 73, 0.5),
+        DecimalTime::new(2024, 10, 0.9),
+        DecimalTime::new(2025, 1, 0.1),
+    ];
+
+    let mut earliest = DecimalTime::MAX;
+    for t in times {
+        if t < earliest {
+            earliest = t;
+        }
+    }
+    assert_eq!(earliest, DecimalTime::new(2024, 10, 0.9));
+}
+
+/// ✅ Test `sort_key` matches chronological order and carries overflowing fields
+#[test]
+fn test_sort_key() {
+    let a = DecimalTime::new(2024, 366, 0.75);
+    let b = DecimalTime::new(2025, 1, 0.25);
+    assert!(a.sort_key() < b.sort_key());
+
+    let carried = DecimalTime { year: 2024, day_of_year: 366, decimal_day: 1.25 };
+    assert_eq!(carried.sort_key(), b.sort_key());
+    assert_eq!(DecimalTime::new(1, 1, 0.0).sort_key(), 0);
+}
+
+/// ✅ Test `unique_instants` collapses mixed representations of the same instant
+#[test]
+fn test_unique_instants() {
+    let noon = DecimalTime::new(2025, 73, 0.5);
+    let times = [
+        noon,
+        DecimalTime { year: 2025, day_of_year: 72, decimal_day: 1.5 },
+        DecimalTime::new(2025, 73, 0.5 + 1e-13), // same microsecond
+        DecimalTime::new(2025, 365, 0.0),
+        DecimalTime { year: 2024, day_of_year: 367, decimal_day: 0.0 }, // 2025-001
+        DecimalTime::new(2025, 1, 0.0),
+    ];
+
+    let unique = DecimalTime::unique_instants(&times);
+    assert_eq!(unique.len(), 3);
+    assert_eq!(unique[0], noon);
+    assert_eq!(unique[2], times[4]);
+}
+
+/// A clock frozen at a fixed instant, standing in for an RTC.
+struct FixedClock(chrono::DateTime<Utc>);
+
+impl decimal_time::Clock for FixedClock {
+    fn now_utc(&self) -> chrono::DateTime<Utc> {
+        self.0
+    }
+}
+
+/// ✅ Test `now_with_dyn_clock` reads from a `&dyn Clock`
+#[test]
+fn test_now_with_dyn_clock() {
+    let clock = FixedClock(Utc.with_ymd_and_hms(2025, 3, 14, 12, 0, 0).unwrap());
+    let dyn_clock: &dyn decimal_time::Clock = &clock;
+
+    assert_eq!(DecimalTime::now_with_dyn_clock(dyn_clock), DecimalTime::new(2025, 73, 0.5));
+}
+
+/// ✅ Test `elapsed_with_dyn_clock` against a fixed "now"
+#[test]
+fn test_elapsed_with_dyn_clock() {
+    let clock = FixedClock(Utc.with_ymd_and_hms(2025, 3, 14, 12, 0, 0).unwrap());
+
+    assert_eq!(
+        DecimalTime::new(2025, 72, 0.25).elapsed_with_dyn_clock(&clock),
+        DecimalDuration::from_decimal_days(1.25)
+    );
+    assert_eq!(
+        DecimalTime::new(2025, 73, 0.75).elapsed_with_dyn_clock(&clock),
+        DecimalDuration::from_decimal_days(-0.25)
+    );
+}
+
+/// ✅ Test `elapsed` is non-negative for a time in the past
+#[test]
+fn test_elapsed() {
+    assert!(DecimalTime::new(2025, 73, 0.5).elapsed() > DecimalDuration::ZERO);
+}
+
+/// ✅ Test `now` reads the system clock
+#[test]
+fn test_now() {
+    let before = DecimalTime::from_datetime_utc(Utc::now());
+    let now = DecimalTime::now();
+
+    assert!(now >= before);
+}
+
+/// ✅ Test `parse_from_str` reads back what `format` wrote
+#[test]
+fn test_parse_from_str_round_trip() {
+    let fmt = "Year=%Y Day=%d Fraction=%f";
+    for dec in [
+        DecimalTime::new(2025, 100, 0.123456),
+        DecimalTime::new(2025, 5, 0.5),
+        DecimalTime::new(-44, 75, 0.0),
+    ] {
+        assert_eq!(DecimalTime::parse_from_str(&dec.format(fmt), fmt), Ok(dec));
+    }
+}
+
+/// ✅ Test an unsigned `%Y` directly followed by a number reads four digits, like chrono
+#[test]
+fn test_parse_from_str_adjacent_year() {
+    for dec in [DecimalTime::new(2025, 100, 0.5), DecimalTime::new(1000, 5, 0.25), DecimalTime::new(9999, 365, 0.0)] {
+        for fmt in ["%Y%d%f", "%Y%j.%f", "%Y%d%T"] {
+            let dec = if fmt == "%Y%d%T" { DecimalTime::new(dec.year, dec.day_of_year, 0.5) } else { dec };
+            assert_eq!(DecimalTime::parse_from_str(&dec.format(fmt), fmt), Ok(dec), "{}", fmt);
+        }
+    }
+
+    let dec = DecimalTime::new(7, 5, 0.25);
+    assert_eq!(DecimalTime::parse_from_str(&dec.format("%04Y%03j%f"), "%04Y%03j%f"), Ok(dec));
+    assert_eq!(DecimalTime::parse_from_str("002025100", "%06Y%d"), Ok(DecimalTime::new(2025, 100, 0.0)));
+}
+
+/// ❌ Test years outside 1000..=9999 do not round-trip through `%Y` followed by a number
+#[test]
+fn test_parse_from_str_adjacent_year_limit() {
+    let dec = DecimalTime::new(12_345, 100, 0.5);
+    assert_ne!(DecimalTime::parse_from_str(&dec.format("%Y%d%f"), "%Y%d%f"), Ok(dec));
+    assert_eq!(DecimalTime::parse_from_str(&dec.format("%Y-%d%f"), "%Y-%d%f"), Ok(dec));
+
+    let dec = DecimalTime::new(7, 5, 0.25);
+    assert_ne!(DecimalTime::parse_from_str(&dec.format("%Y%d%f"), "%Y%d%f"), Ok(dec));
+
+    let dec = DecimalTime::new(-44, 75, 0.0);
+    assert!(DecimalTime::parse_from_str(&dec.format("%Y%d"), "%Y%d").is_err());
+}
+
+/// ✅ Test `parse_from_str` with the CLI layout and a leading zero fraction
+#[test]
+fn test_parse_from_str_layouts() {
+    assert_eq!(
+        DecimalTime::parse_from_str("2025.73.25", "%Y.%d%f"),
+        Ok(DecimalTime::new(2025, 73, 0.25))
+    );
+    assert_eq!(
+        DecimalTime::parse_from_str("2025/073 0.75", "%Y/%d %f"),
+        Ok(DecimalTime::new(2025, 73, 0.75))
+    );
+}
+
+/// ❌ Test `parse_from_str` fails on literal mismatches and bad fields
+#[test]
+fn test_parse_from_str_invalid() {
+    assert!(matches!(
+        DecimalTime::parse_from_str("Year:2025 Day=100", "Year=%Y Day=%d"),
+        Err(ParseError::Malformed(_))
+    ));
+    assert!(matches!(
+        DecimalTime::parse_from_str("2025-100 extra", "%Y-%d"),
+        Err(ParseError::Malformed(_))
+    ));
+    assert!(matches!(DecimalTime::parse_from_str("2025", "%Y"), Err(ParseError::Malformed(_))));
+    assert!(matches!(
+        DecimalTime::parse_from_str("2025-366", "%Y-%d"),
+        Err(ParseError::OutOfRange(DecimalTimeError::DayOfYearOutOfRange { .. }))
+    ));
+}
+
+/// ✅ Test `%e` renders decimal days since a custom epoch
+#[test]
+fn test_format_since_epoch() {
+    let epoch = DecimalTime::new(2025, 60, 0.25);
+
+    assert_eq!(DecimalTime::new(2025, 61, 0.25).format_since(&epoch, "T+%e"), "T+1");
+    assert_eq!(DecimalTime::new(2025, 62, 0.75).format_since(&epoch, "%Y-%d T+%e"), "2025-062 T+2.5");
+    assert_eq!(DecimalTime::new(2025, 60, 0.0).format_since(&epoch, "T%e"), "T-0.25");
+}
+
+/// ✅ Test conversion from Unix milliseconds
+#[test]
+fn test_from_unix_timestamp_millis() {
+    assert_eq!(DecimalTime::from_unix_timestamp_millis(0), Some(DecimalTime::new(1970, 1, 0.0)));
+    assert_eq!(DecimalTime::from_unix_timestamp_millis(-43_200_000), Some(DecimalTime::new(1969, 365, 0.5)));
+    assert_eq!(DecimalTime::from_unix_timestamp_millis(i64::MAX), None);
+}
+
+/// ✅ Test round trip through `SystemTime`
+#[test]
+fn test_system_time_round_trip() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let t = UNIX_EPOCH + Duration::from_millis(1_741_964_400_250); // 2025-03-14T15:00:00.250Z
+    let dec = DecimalTime::from_system_time(t).unwrap();
+
+    assert_eq!((dec.year, dec.day_of_year), (2025, 73));
+    assert_eq!(dec.to_system_time(), t);
+    assert!(DecimalTime::from_system_time(SystemTime::now()).is_some());
+}
+
+/// ❌ Test times before the Unix epoch are rejected
+#[test]
+fn test_from_system_time_before_epoch() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    assert_eq!(DecimalTime::from_system_time(UNIX_EPOCH - Duration::from_secs(1)), None);
+    assert_eq!(
+        DecimalTime::new(1969, 365, 0.5).to_system_time(),
+        UNIX_EPOCH - Duration::from_secs(43_200)
+    );
+}
+
+/// ✅ Test a 1-day-6-hour span in conventional components
+#[test]
+fn test_chrono_components_since() {
+    let earlier = DecimalTime::new(2025, 73, 0.5);
+    let later = DecimalTime::new(2025, 74, 0.75);
+
+    assert_eq!(later.chrono_components_since(&earlier), Some((1, 6, 0, 0)));
+    assert_eq!(
+        DecimalTime::new(2025, 73, 0.5 + 3_661.0 / 86_400.0).chrono_components_since(&earlier),
+        Some((0, 1, 1, 1))
+    );
+}
+
+/// ❌ Test `chrono_components_since` rejects reversed and invalid spans
+#[test]
+fn test_chrono_components_since_invalid() {
+    let earlier = DecimalTime::new(2025, 73, 0.5);
+    let invalid = DecimalTime { year: 2025, day_of_year: 366, decimal_day: 0.0 };
+
+    assert_eq!(earlier.chrono_components_since(&DecimalTime::new(2025, 74, 0.5)), None);
+    assert_eq!(invalid.chrono_components_since(&earlier), None);
+}
+
+/// ✅ Test `%d` is zero-padded and `%j` is not
+#[test]
+fn test_format_day_padding() {
+    let dec = DecimalTime::new(2025, 5, 0.5);
+
+    assert_eq!(dec.format("%Y-%d"), "2025-005");
+    assert_eq!(dec.format("%Y-%j"), "2025-5");
+    assert_eq!(DecimalTime::new(2025, 73, 0.0).format("%d/%j"), "073/73");
+}
+
+/// ✅ Test `parse_from_str` accepts `%j`
+#[test]
+fn test_parse_from_str_unpadded_day() {
+    let dec = DecimalTime::new(2025, 5, 0.25);
+    assert_eq!(DecimalTime::parse_from_str(&dec.format("%Y.%j%f"), "%Y.%j%f"), Ok(dec));
+}
+
+/// ✅ Test monotonic sequence checks on a sorted slice
+#[test]
+fn test_is_sorted_ascending() {
+    let times = [
+        DecimalTime::new(2024, 366, 0.9),
+        DecimalTime::new(2025, 1, 0.1),
+        DecimalTime::new(2025, 1, 0.1),
+        DecimalTime::new(2025, 2, 0.0),
+    ];
+
+    assert!(DecimalTime::is_sorted_ascending(&times));
+    assert_eq!(DecimalTime::first_out_of_order(&times), None);
+}
+
+/// ❌ Test monotonic sequence checks find a regression
+#[test]
+fn test_first_out_of_order() {
+    let times = [
+        DecimalTime::new(2025, 1, 0.1),
+        DecimalTime::new(2025, 1, 0.5),
+        DecimalTime::new(2025, 1, 0.3),
+        DecimalTime::new(2025, 2, 0.0),
+    ];
+
+    assert!(!DecimalTime::is_sorted_ascending(&times));
+    assert_eq!(DecimalTime::first_out_of_order(&times), Some(2));
+}
+
+/// ✅ Test monotonic sequence checks on an empty slice
+#[test]
+fn test_is_sorted_ascending_empty() {
+    assert!(DecimalTime::is_sorted_ascending(&[]));
+    assert_eq!(DecimalTime::first_out_of_order(&[]), None);
+}
+
+/// ✅ Test `saturating_add` / `saturating_sub` near chrono's date limits
+#[test]
+fn test_saturating_add_sub() {
+    let near_max = DecimalTime::new(262_142, 365, 0.5);
+    let near_min = DecimalTime::new(-262_143, 1, 0.5);
+    let two_days = chrono::Duration::days(2);
+
+    assert_eq!(near_max.saturating_add(two_days), DecimalTime::MAX);
+    assert_eq!(near_min.saturating_sub(two_days), DecimalTime::MIN);
+    assert_eq!(near_max.saturating_sub(-two_days), DecimalTime::MAX);
+    assert_eq!(near_min.saturating_add(-two_days), DecimalTime::MIN);
+
+    let in_range = near_max.saturating_sub(two_days);
+    assert_eq!(in_range, DecimalTime::new(262_142, 363, 0.5));
+}
+
+/// ❌ Test `saturating_add` still panics on an invalid date
+#[test]
+#[should_panic]
+fn test_saturating_add_invalid_date() {
+    let invalid = DecimalTime { year: 2025, day_of_year: 366, decimal_day: 0.0 };
+    let _ = invalid.saturating_add(chrono::Duration::days(1));
+}
+
+/// ✅ Test `%r` renders the conventional time into the day
+#[test]
+fn test_format_conventional_clock() {
+    assert_eq!(DecimalTime::new(2025, 73, 0.5).format("%r"), "12:00:00");
+    assert_eq!(DecimalTime::new(2025, 73, 0.25).format("%Y-%d %r"), "2025-073 06:00:00");
+    assert_eq!(DecimalTime::new(2025, 73, 0.0).format("%r"), "00:00:00");
+}
+
+/// ✅ Test `parse_from_str` reads `%r` back
+#[test]
+fn test_parse_from_str_conventional_clock() {
+    assert_eq!(
+        DecimalTime::parse_from_str("2025-073 18:00:00", "%Y-%d %r"),
+        Ok(DecimalTime::new(2025, 73, 0.75))
+    );
+    assert!(DecimalTime::parse_from_str("2025-073 24:00:00", "%Y-%d %r").is_err());
+}
+
+/// ✅ Test `wall_clock` reads off the conventional time of day
+#[test]
+fn test_wall_clock() {
+    assert_eq!(DecimalTime::new(2025, 73, 0.5).wall_clock(), (12, 0, 0, 0));
+    assert_eq!(DecimalTime::new(2025, 73, 0.75).wall_clock(), (18, 0, 0, 0));
+}
+
+/// ✅ Test `wall_clock` agrees with `to_naive_datetime` to the microsecond
+#[test]
+fn test_wall_clock_matches_naive_datetime() {
+    for i in 0..10_000 {
+        let dec = DecimalTime::new(2025, 73, i as f64 / 10_000.0 + 0.000_012_345);
+        let time = dec.to_naive_datetime().time();
+
+        assert_eq!(
+            dec.wall_clock(),
+            (time.hour(), time.minute(), time.second(), time.nanosecond() / 1_000)
+        );
+    }
+}
+
+/// ✅ Test date-only comparisons ignore the time of day
+#[test]
+fn test_same_day_and_cmp_date() {
+    use std::cmp::Ordering;
+
+    let morning = DecimalTime::new(2025, 73, 0.25);
+    let evening = DecimalTime::new(2025, 73, 0.75);
+    let next_year = DecimalTime::new(2026, 1, 0.0);
+
+    assert!(morning.same_day(&evening));
+    assert!(!morning.same_day(&next_year));
+    assert_eq!(evening.cmp_date(&morning), Ordering::Equal);
+    assert_eq!(evening.cmp_date(&next_year), Ordering::Less);
+    assert_eq!(next_year.cmp_date(&morning), Ordering::Greater);
+}
+
+/// ✅ Test the compact string form
+#[test]
+fn test_to_compact_string() {
+    assert_eq!(DecimalTime::new(2025, 73, 0.5).to_compact_string(), "2025.73.5");
+    assert_eq!(DecimalTime::new(2025, 5, 0.25).to_compact_string(), "2025.5.25");
+    assert_eq!(DecimalTime::new(2025, 5, 0.0).to_compact_string(), "2025.5");
+}
+
+/// ✅ Test the compact string form round-trips
+#[test]
+fn test_compact_string_round_trip() {
+    for dec in [
+        DecimalTime::new(2025, 73, 0.5),
+        DecimalTime::new(2025, 5, 0.0),
+        DecimalTime::new(2024, 366, 0.123456),
+    ] {
+        assert_eq!(DecimalTime::from_compact_string(&dec.to_compact_string()), Ok(dec));
+    }
+    assert!(DecimalTime::from_compact_string("2025-73.5").is_err());
+}
+
+/// ✅ Test `try_format` accepts every known specifier and `%%`
+#[test]
+fn test_try_format() {
+    let dec = DecimalTime::new(2025, 5, 0.5);
+
+    assert_eq!(dec.try_format("%Y.%d%f (%j) %r"), Ok(dec.format("%Y.%d%f (%j) %r")));
+    assert_eq!(dec.try_format("%%Y is %Y"), Ok("%Y is 2025".to_string()));
+}
+
+/// ❌ Test `try_format` rejects unknown specifiers and a trailing `%`
+#[test]
+fn test_try_format_invalid() {
+    let dec = DecimalTime::new(2025, 5, 0.5);
+
+    assert_eq!(
+        dec.try_format("%Y-%D"),
+        Err(FormatError::UnknownSpecifier { specifier: 'D', position: 3 })
+    );
+    assert_eq!(dec.try_format("%Y%"), Err(FormatError::TrailingPercent));
+    assert_eq!(dec.format("%Y-%D"), "2025-%D"); // `format` stays lenient
+}
+
+/// ✅ Test `%%` renders a literal percent sign
+#[test]
+fn test_format_percent_escape() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+
+    assert_eq!(dec.format("%Y%%"), "2025%");
+    assert_eq!(dec.format("%%Y is %Y"), "%Y is 2025");
+    assert_eq!(dec.format("100%"), "100%");
+    assert_eq!(dec.format_since(&dec, "%e%%"), "0%");
+}
+
+/// ✅ Test `nearest_representable` makes an arbitrary fraction round-trip exactly
+#[test]
+fn test_nearest_representable() {
+    let dec = DecimalTime::new(2025, 73, 0.123_456_789_123);
+    let snapped = dec.nearest_representable();
+
+    assert!(snapped.approx_eq(&dec, 1e-11));
+    assert_eq!(DecimalTime::from_naive_datetime(snapped.to_naive_datetime()), snapped);
+    assert_eq!(snapped.nearest_representable(), snapped);
+}
+
+/// ✅ Test `nearest_representable` carries into the next day
+#[test]
+fn test_nearest_representable_carry() {
+    let dec = DecimalTime::new(2024, 366, 0.999_999_999_999);
+    assert_eq!(dec.nearest_representable(), DecimalTime::new(2025, 1, 0.0));
+}
+
+/// ✅ Test `DecimalDate::at` validates the fraction
+#[test]
+fn test_decimal_date_at() {
+    let date = DecimalDate::new(2025, 73).unwrap();
+
+    assert_eq!(date.at(0.5), Ok(DecimalTime::new(2025, 73, 0.5)));
+    assert_eq!(date.at(1.5), Err(DecimalTimeError::DecimalDayOutOfRange(1.5)));
+    assert_eq!(DecimalTime::new(2025, 73, 0.9).date(), date);
+    assert!(DecimalDate::new(2025, 366).is_err());
+}
+
+/// ✅ Test `with_fraction_wrapping` carries whole days forwards and backwards
+#[test]
+fn test_decimal_date_with_fraction_wrapping() {
+    let date = DecimalDate::new(2025, 73).unwrap();
+
+    assert_eq!(date.with_fraction_wrapping(2.25), DecimalTime::new(2025, 75, 0.25));
+    assert_eq!(date.with_fraction_wrapping(0.5), DecimalTime::new(2025, 73, 0.5));
+    assert_eq!(date.with_fraction_wrapping(-0.25), DecimalTime::new(2025, 72, 0.75));
+
+    let new_years_eve = DecimalDate::new(2024, 366).unwrap();
+    assert_eq!(new_years_eve.with_fraction_wrapping(1.5), DecimalTime::new(2025, 1, 0.5));
+}
+
+/// ✅ Test `from_date_and_fraction` takes year and ordinal from the date
+#[test]
+fn test_from_date_and_fraction() {
+    let date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    assert_eq!(
+        DecimalTime::from_date_and_fraction(date, 0.25),
+        Ok(DecimalTime::new(2024, 366, 0.25))
+    );
+}
+
+/// ❌ Test `from_date_and_fraction` rejects fractions out of [0,1)
+#[test]
+fn test_from_date_and_fraction_invalid() {
+    let date = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    assert_eq!(
+        DecimalTime::from_date_and_fraction(date, 1.0),
+        Err(DecimalTimeError::DecimalDayOutOfRange(1.0))
+    );
+    assert!(DecimalTime::from_date_and_fraction(date, f64::NAN).is_err());
+}
+
+/// ✅ Test `checked_add_decimal_*` steps by decimal clock units
+#[test]
+fn test_checked_add_decimal_units() {
+    let dt = DecimalTime::new(2025, 73, 0.5);
+
+    assert_eq!(dt.checked_add_decimal_hours(2.5), Some(DecimalTime::new(2025, 73, 0.75)));
+    assert_eq!(dt.checked_add_decimal_hours(-5.0), Some(DecimalTime::new(2025, 73, 0.0)));
+    assert_eq!(dt.checked_add_decimal_minutes(750.0), Some(DecimalTime::new(2025, 74, 0.25)));
+    assert_eq!(dt.checked_add_decimal_seconds(25_000.0), Some(DecimalTime::new(2025, 73, 0.75)));
+}
+
+/// ❌ Test `checked_add_decimal_*` returns `None` on overflow or non-finite input
+#[test]
+fn test_checked_add_decimal_units_overflow() {
+    let max = DecimalTime::max_value();
+
+    assert_eq!(max.checked_add_decimal_hours(1.0), None);
+    assert_eq!(DecimalTime::min_value().checked_add_decimal_minutes(-1.0), None);
+    assert_eq!(max.checked_add_decimal_seconds(f64::NAN), None);
+    assert_eq!(DecimalTime::new(2025, 73, 0.5).checked_add_decimal_hours(f64::INFINITY), None);
+    assert_eq!(DecimalTime::new(2025, 73, 0.5).checked_add_decimal_hours(1e300), None);
+}
+
+/// ✅ Test `midnight`, `noon` and `start_of_year`
+#[test]
+fn test_midnight_noon_start_of_year() {
+    assert_eq!(DecimalTime::midnight(2025, 73), Ok(DecimalTime::new(2025, 73, 0.0)));
+    assert_eq!(DecimalTime::noon(2024, 366), Ok(DecimalTime::new(2024, 366, 0.5)));
+    assert_eq!(DecimalTime::start_of_year(2025), Ok(DecimalTime::new(2025, 1, 0.0)));
+}
+
+/// ❌ Test `midnight` and `noon` reject days past the end of the year
+#[test]
+fn test_midnight_noon_invalid_day() {
+    let err = DecimalTimeError::DayOfYearOutOfRange { year: 2025, day_of_year: 366 };
+    assert_eq!(DecimalTime::midnight(2025, 366), Err(err));
+    assert_eq!(DecimalTime::noon(2025, 366), Err(err));
+    assert!(DecimalTime::noon(2025, 0).is_err());
+}
+
+/// ❌ Test `start_of_year` rejects years outside `MIN_YEAR..=MAX_YEAR`
+#[test]
+fn test_start_of_year_out_of_range() {
+    assert_eq!(DecimalTime::start_of_year(300_000), Err(DecimalTimeError::YearOutOfRange(300_000)));
+    assert_eq!(DecimalTime::start_of_year(i32::MIN), Err(DecimalTimeError::YearOutOfRange(i32::MIN)));
+    assert!(DecimalTime::start_of_year(DecimalTime::MAX_YEAR).is_ok());
+}
+
+/// ✅ Test ISO 8601 round trip keeps sub-second precision
+#[test]
+fn test_iso8601_round_trip() {
+    let noon = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!(noon.to_iso8601(), "2025-03-14T12:00:00+00:00");
+    assert_eq!(DecimalTime::from_iso8601("2025-03-14T12:00:00+00:00"), Ok(noon));
+
+    let precise = DecimalTime::from_iso8601("2025-03-14T12:00:00.123456Z").unwrap();
+    assert_eq!(precise.to_iso8601(), "2025-03-14T12:00:00.123456+00:00");
+    assert_eq!(DecimalTime::from_iso8601(&precise.to_iso8601()), Ok(precise));
+}
+
+/// ✅ Test `from_iso8601` converts offsets to UTC
+#[test]
+fn test_from_iso8601_offset() {
+    assert_eq!(
+        DecimalTime::from_iso8601("2025-03-14T13:00:00+01:00"),
+        Ok(DecimalTime::new(2025, 73, 0.5))
+    );
+}
+
+/// ❌ Test `from_iso8601` rejects malformed input
+#[test]
+fn test_from_iso8601_malformed() {
+    assert!(matches!(
+        DecimalTime::from_iso8601("2025-073.5"),
+        Err(ParseError::Malformed(_))
+    ));
+}
+
+/// ✅ Test `%'Y` renders a seven-digit year with thousands separators
+#[test]
+fn test_format_year_thousands_separator() {
+    let dec = DecimalTime::new_unchecked(1_000_000, 73, 0.5);
+
+    assert_eq!(dec.format("%Y-%d"), "1000000-073");
+    assert_eq!(dec.format("%'Y-%d"), "1,000,000-073");
+    assert_eq!(dec.format_with_thousands_separator("%'Y", '.'), "1.000.000");
+    assert_eq!(DecimalTime::new_unchecked(-1_234_567, 1, 0.0).format("%'Y"), "-1,234,567");
+    assert_eq!(DecimalTime::new(999, 1, 0.0).format("%'Y"), "999");
+}
+
+/// ❌ Test a `%'` not followed by `Y` is not a token
+#[test]
+fn test_format_thousands_separator_requires_year() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+
+    assert_eq!(dec.format("%'d"), "%'d");
+    assert_eq!(
+        dec.try_format("%'d"),
+        Err(FormatError::UnknownSpecifier { specifier: '\'', position: 0 })
+    );
+}
+
+/// ✅ Test a fraction that rounds up to a full day rolls into the next day's midnight
+#[test]
+fn test_to_naive_datetime_rounds_to_next_midnight() {
+    // 0.9999999999 is still 8.64µs short of a full day, so it stays on the same day
+    let almost = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap().and_hms_micro_opt(23, 59, 59, 999_991).unwrap();
+    assert_eq!(DecimalTime::new(2025, 73, 0.9999999999).to_naive_datetime(), almost);
+
+    // Within half a microsecond of 1.0 the fraction rounds up to a full day
+    let expected = NaiveDate::from_ymd_opt(2025, 3, 15).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    assert_eq!(DecimalTime::new(2025, 73, 0.9999999999999).to_naive_datetime(), expected);
+    assert_eq!(DecimalTime::new(2025, 73, 0.9999999999999).to_naive_datetime_opt(), Some(expected));
+
+    let new_year = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    assert_eq!(DecimalTime::new(2024, 366, 0.9999999999999).to_naive_datetime(), new_year);
+    assert_eq!(
+        DecimalTime::from_naive_datetime(DecimalTime::new(2024, 366, 0.9999999999999).to_naive_datetime()),
+        DecimalTime::new(2025, 1, 0.0)
+    );
+}
+
+/// ✅ Test `wrap_fraction` wraps the fraction without touching the date
+#[test]
+fn test_wrap_fraction() {
+    let forward = DecimalTime::new(2025, 73, 0.9).wrap_fraction(0.2);
+    assert_eq!((forward.year, forward.day_of_year), (2025, 73));
+    assert!((forward.decimal_day - 0.1).abs() < 1e-12);
+
+    let backward = DecimalTime::new(2025, 73, 0.1).wrap_fraction(-0.2);
+    assert_eq!((backward.year, backward.day_of_year), (2025, 73));
+    assert!((backward.decimal_day - 0.9).abs() < 1e-12);
+
+    assert_eq!(DecimalTime::new(2025, 365, 0.25).wrap_fraction(3.5), DecimalTime::new(2025, 365, 0.75));
+    assert_eq!(DecimalTime::new(2025, 73, 0.0).wrap_fraction(-1e-20), DecimalTime::new(2025, 73, 0.0));
+}
+
+/// ❌ Test `wrap_fraction` panics on a non-finite delta
+#[test]
+#[should_panic(expected = "`delta` must be finite")]
+fn test_wrap_fraction_non_finite() {
+    DecimalTime::new(2025, 73, 0.5).wrap_fraction(f64::NAN);
+}
+
+/// ✅ Test protobuf-style `(seconds, nanos)` round trips
+#[test]
+fn test_prost_timestamp_round_trip() {
+    let epoch = DecimalTime::new(1970, 1, 0.0);
+    assert_eq!(epoch.to_prost_timestamp(), Some((0, 0)));
+    assert_eq!(DecimalTime::from_prost_timestamp(0, 0), Some(epoch));
+
+    let noon = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!(noon.to_prost_timestamp(), Some((1_741_953_600, 0)));
+
+    let precise = DecimalTime::from_prost_timestamp(1_741_953_600, 250_500_000).unwrap();
+    assert_eq!(precise.to_prost_timestamp(), Some((1_741_953_600, 250_500_000)));
+
+    let before_epoch = DecimalTime::from_prost_timestamp(-1, 500_000_000).unwrap();
+    assert_eq!(before_epoch.to_prost_timestamp(), Some((-1, 500_000_000)));
+}
+
+/// ❌ Test invalid protobuf-style timestamps are rejected
+#[test]
+fn test_prost_timestamp_invalid() {
+    assert_eq!(DecimalTime::from_prost_timestamp(0, -1), None);
+    assert_eq!(DecimalTime::from_prost_timestamp(0, 1_000_000_000), None);
+    assert_eq!(DecimalTime::from_prost_timestamp(i64::MAX, 0), None);
+    assert_eq!(DecimalTime::new_unchecked(2025, 366, 0.5).to_prost_timestamp(), None);
+}
+
+/// ✅ Test `from_naive_time` matches `from_naive_datetime`
+#[test]
+fn test_from_naive_time() {
+    let t = chrono::NaiveTime::from_hms_micro_opt(18, 0, 0, 250).unwrap();
+    let ndt = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap().and_time(t);
+
+    assert_eq!(DecimalTime::from_naive_time(2024, 366, t), Ok(DecimalTime::from_naive_datetime(ndt)));
+    assert_eq!(
+        DecimalTime::from_naive_time(2025, 73, chrono::NaiveTime::from_hms_opt(12, 0, 0).unwrap()),
+        Ok(DecimalTime::new(2025, 73, 0.5))
+    );
+}
+
+/// ❌ Test `from_naive_time` validates the day against the year
+#[test]
+fn test_from_naive_time_invalid_day() {
+    let t = chrono::NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+    assert_eq!(
+        DecimalTime::from_naive_time(2025, 366, t),
+        Err(DecimalTimeError::DayOfYearOutOfRange { year: 2025, day_of_year: 366 })
+    );
+}
+
+/// ✅ Test `checked_add_days` crosses a leap year and keeps the fraction
+#[test]
+fn test_checked_add_days() {
+    let dt = DecimalTime::new(2023, 300, 0.123456789);
+
+    // 65 days to the end of 2023, then all 366 days of 2024
+    assert_eq!(dt.checked_add_days(400), Some(DecimalTime::new(2024, 335, 0.123456789)));
+    assert_eq!(dt.checked_add_days(65 + 366 + 1), Some(DecimalTime::new(2025, 1, 0.123456789)));
+    assert_eq!(dt.checked_add_days(400).unwrap().checked_sub_days(400), Some(dt));
+    assert_eq!(dt.checked_add_days(-299), Some(DecimalTime::new(2023, 1, 0.123456789)));
+}
+
+/// ❌ Test `checked_add_days` returns `None` at the representable limits and for invalid values
+#[test]
+fn test_checked_add_days_out_of_range() {
+    assert_eq!(DecimalTime::max_value().checked_add_days(1), None);
+    assert_eq!(DecimalTime::min_value().checked_sub_days(1), None);
+    assert_eq!(DecimalTime::new(2025, 73, 0.5).checked_add_days(i64::MIN), None);
+    assert_eq!(DecimalTime::new_unchecked(2025, 366, 0.5).checked_add_days(1), None);
+    assert_eq!(DecimalTime::new_unchecked(2025, 1, 5.0).checked_add_days(1), None);
+    assert_eq!(DecimalTime::new_unchecked(2025, 1, f64::NAN).checked_sub_days(1), None);
+}
+
+/// ✅ Test `weekday` and the `%w` token
+#[test]
+fn test_weekday() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+
+    assert_eq!(dec.weekday(), chrono::Weekday::Fri);
+    assert_eq!(dec.weekday_opt(), Some(chrono::Weekday::Fri));
+    assert_eq!(dec.format("%w %Y-%d"), "Fri 2025-073");
+    assert_eq!(dec.try_format("%w"), Ok("Fri".to_string()));
+}
+
+/// ❌ Test `weekday_opt` is `None` for an invalid date
+#[test]
+fn test_weekday_opt_invalid() {
+    assert_eq!(DecimalTime::new_unchecked(2025, 366, 0.5).weekday_opt(), None);
+}
+
+/// ✅ Test `to_days_since_epoch` and `from_days_since_epoch`
+#[test]
+fn test_days_since_epoch() {
+    assert_eq!(DecimalTime::new(1970, 1, 0.0).to_days_since_epoch(), 0.0);
+    assert_eq!(DecimalTime::new(1970, 2, 0.5).to_days_since_epoch(), 1.5);
+    assert_eq!(DecimalTime::new(1969, 365, 0.75).to_days_since_epoch(), -0.25);
+
+    assert_eq!(DecimalTime::from_days_since_epoch(1.5), Some(DecimalTime::new(1970, 2, 0.5)));
+    assert_eq!(DecimalTime::from_days_since_epoch(-0.25), Some(DecimalTime::new(1969, 365, 0.75)));
+
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!(dec.to_days_since_epoch(), 20_161.5);
+    assert_eq!(DecimalTime::from_days_since_epoch(dec.to_days_since_epoch()), Some(dec));
+}
+
+/// ❌ Test `from_days_since_epoch` rejects non-finite and out-of-range values
+#[test]
+fn test_from_days_since_epoch_invalid() {
+    assert_eq!(DecimalTime::from_days_since_epoch(f64::NAN), None);
+    assert_eq!(DecimalTime::from_days_since_epoch(f64::INFINITY), None);
+    assert_eq!(DecimalTime::from_days_since_epoch(1e12), None);
+    assert_eq!(DecimalTime::from_days_since_epoch(-1e9), None);
+}
+
+/// ✅ Test `normalize` carries excess fields forwards
+#[test]
+fn test_normalize_forward() {
+    let raw = DecimalTime { year: 2025, day_of_year: 365, decimal_day: 1.5 };
+    assert_eq!(raw.normalize(), DecimalTime::new(2026, 1, 0.5));
+
+    let raw = DecimalTime { year: 2024, day_of_year: 400, decimal_day: 0.25 };
+    assert_eq!(raw.normalize(), DecimalTime::new(2025, 34, 0.25));
+
+    let raw = DecimalTime { year: 2000, day_of_year: 1, decimal_day: 146_097.0 * 2.0 + 0.5 };
+    assert_eq!(raw.normalize(), DecimalTime::new(2800, 1, 0.5));
+
+    assert_eq!(DecimalTime::new(2025, 73, 0.5).normalize(), DecimalTime::new(2025, 73, 0.5));
+}
+
+/// ✅ Test `normalize` carries negative overflow backwards
+#[test]
+fn test_normalize_backward() {
+    let raw = DecimalTime { year: 2025, day_of_year: 1, decimal_day: -0.5 };
+    assert_eq!(raw.normalize(), DecimalTime::new(2024, 366, 0.5));
+
+    let raw = DecimalTime { year: 2025, day_of_year: 0, decimal_day: 0.25 };
+    assert_eq!(raw.normalize(), DecimalTime::new(2024, 366, 0.25));
+
+    let raw = DecimalTime { year: 2025, day_of_year: 366, decimal_day: -1.0 };
+    assert_eq!(raw.normalize(), DecimalTime::new(2025, 365, 0.0));
+}
+
+/// ❌ Test `normalize` rejects a non-finite fraction
+#[test]
+#[should_panic(expected = "`decimal_day` must be finite")]
+fn test_normalize_non_finite() {
+    DecimalTime { year: 2025, day_of_year: 1, decimal_day: f64::INFINITY }.normalize();
+}
+
+/// ✅ Test comparing a `DecimalTime` directly with a `NaiveDateTime`
+#[test]
+fn test_partial_eq_naive_datetime() {
+    let noon = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let dec = DecimalTime::new(2025, 73, 0.5);
+
+    assert_eq!(dec, noon);
+    assert_eq!(noon, dec);
+    assert_ne!(DecimalTime::new(2025, 73, 0.25), noon);
+
+    // Sub-microsecond nanoseconds are truncated before comparing
+    let precise = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap().and_hms_nano_opt(12, 0, 0, 123_456_789).unwrap();
+    assert_eq!(DecimalTime::from_naive_datetime(precise), precise);
+}
+
+/// ❌ Test an invalid `DecimalTime` never equals a `NaiveDateTime`
+#[test]
+fn test_partial_eq_naive_datetime_invalid() {
+    let dt = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    assert_ne!(DecimalTime::new_unchecked(2025, 366, 0.5), dt);
+}
+
+/// ✅ Test `%T` and `%R` render the conventional wall-clock time
+#[test]
+fn test_format_conventional_clock_tokens() {
+    let dec = DecimalTime::from_naive_datetime(
+        NaiveDate::from_ymd_opt(2025, 3, 14).unwrap().and_hms_opt(15, 9, 26).unwrap(),
+    );
+
+    assert_eq!(dec.format("%T"), "15:09:26");
+    assert_eq!(dec.format("%R"), "15:09");
+    assert_eq!(DecimalTime::new(2025, 73, 0.5).format("%Y.%d%f (%T)"), "2025.073.5 (12:00:00)");
+    assert_eq!(DecimalTime::new(2025, 73, 0.0).try_format("%R"), Ok("00:00".to_string()));
+}
+
+/// ✅ Test every second of a day survives the round trip within 1 microsecond
+#[test]
+fn test_round_trip_error_every_second() {
+    let midnight = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+    for second in 0..86_400 {
+        let dt = midnight + chrono::Duration::seconds(second);
+        let error = DecimalTime::round_trip_error(dt);
+        assert!(error.abs() <= 1, "{} round-tripped with an error of {}µs", dt, error);
+    }
+}
+
+/// ✅ Test `round_trip_error` ignores sub-microsecond nanoseconds
+#[test]
+fn test_round_trip_error_sub_microsecond() {
+    let dt = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap().and_hms_nano_opt(23, 59, 59, 999_999_999).unwrap();
+    assert_eq!(DecimalTime::round_trip_error(dt), 0);
+}
+
+/// ✅ Test `next_day_of_decade` within a décade and into the next one
+#[test]
+fn test_next_day_of_decade() {
+    let dec = DecimalTime::new(2025, 73, 0.25);
+
+    assert_eq!(dec.next_day_of_decade(5), DecimalTime::new(2025, 75, 0.25));
+    assert_eq!(dec.next_day_of_decade(3), DecimalTime::new(2025, 83, 0.25));
+    assert_eq!(dec.next_day_of_decade(1), DecimalTime::new(2025, 81, 0.25));
+}
+
+/// ✅ Test `next_day_of_decade` across the year-end partial décade
+#[test]
+fn test_next_day_of_decade_year_end() {
+    assert_eq!(DecimalTime::new(2025, 362, 0.5).next_day_of_decade(5), DecimalTime::new(2025, 365, 0.5));
+    assert_eq!(DecimalTime::new(2025, 362, 0.5).next_day_of_decade(8), DecimalTime::new(2026, 8, 0.5));
+    assert_eq!(DecimalTime::new(2024, 362, 0.5).next_day_of_decade(6), DecimalTime::new(2024, 366, 0.5));
+    assert_eq!(DecimalTime::new(2025, 365, 0.5).next_day_of_decade(1), DecimalTime::new(2026, 1, 0.5));
+}
+
+/// ❌ Test `next_day_of_decade` rejects a target outside 1..=10
+#[test]
+#[should_panic(expected = "`target` must be in [1..=10]")]
+fn test_next_day_of_decade_invalid_target() {
+    DecimalTime::new(2025, 73, 0.5).next_day_of_decade(11);
+}
+
+/// ✅ Test `seconds_of_day` and `from_seconds_of_day`
+#[test]
+fn test_seconds_of_day() {
+    assert_eq!(DecimalTime::from_seconds_of_day(2025, 73, 21_600.0), Ok(DecimalTime::new(2025, 73, 0.25)));
+    assert_eq!(DecimalTime::new(2025, 73, 0.25).seconds_of_day(), 21_600.0);
+    assert_eq!(DecimalTime::new(2025, 73, 0.0).seconds_of_day(), 0.0);
+
+    let almost = DecimalTime::from_seconds_of_day(2025, 73, 86_399.999_999_999_99).unwrap();
+    assert!(almost.decimal_day < 1.0);
+}
+
+/// ❌ Test `from_seconds_of_day` rejects seconds outside [0, 86400)
+#[test]
+fn test_from_seconds_of_day_invalid() {
+    assert_eq!(
+        DecimalTime::from_seconds_of_day(2025, 73, 86_400.0),
+        Err(DecimalTimeError::DecimalDayOutOfRange(1.0))
+    );
+    assert!(DecimalTime::from_seconds_of_day(2025, 73, -1.0).is_err());
+    assert!(DecimalTime::from_seconds_of_day(2025, 73, f64::NAN).is_err());
+    assert!(DecimalTime::from_seconds_of_day(2025, 366, 0.0).is_err());
+}
+
+/// ✅ Test `%p` and `%.Np` render the day as a percentage
+#[test]
+fn test_format_percentage() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+
+    assert_eq!(dec.format("%p"), "50%");
+    assert_eq!(dec.format("%.1p"), "50.0%");
+    assert_eq!(DecimalTime::new(2025, 73, 0.123456).format("%.2p done"), "12.35% done");
+    assert_eq!(DecimalTime::new(2025, 73, 0.0).try_format("%.0p"), Ok("0%".to_string()));
+}
+
+/// ✅ Test `%p` writes the shortest fraction as a percentage, without float noise
+#[test]
+fn test_format_percentage_shortest() {
+    let percent = |fraction: f64| DecimalTime::new_unchecked(2025, 73, fraction).format("%p");
+
+    assert_eq!(percent(0.07), "7%");
+    assert_eq!(percent(0.29), "29%");
+    assert_eq!(percent(0.123), "12.3%");
+    assert_eq!(percent(0.001), "0.1%");
+    assert_eq!(percent(0.000_25), "0.025%");
+    assert_eq!(percent(0.0), "0%");
+    assert_eq!(percent(-0.0), "0%");
+    assert_eq!(percent(0.999_999), "99.9999%");
+    assert_eq!(percent(1.5), "150%");
+    assert_eq!(percent(-0.5), "-50%");
+    assert_eq!(percent(f64::NAN), "NaN%");
+
+    for fraction in [0.07, 0.29, 0.123_456_789] {
+        let dec = DecimalTime::new(2025, 73, fraction);
+        assert_eq!(DecimalTime::parse_from_str(&dec.format("%Y-%d %p"), "%Y-%d %p"), Ok(dec));
+    }
+}
+
+/// ❌ Test a `%.` not followed by digits and `p` (or `f`) is not a token
+#[test]
+fn test_format_percentage_malformed() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+
+    assert_eq!(dec.format("%.p"), "%.p");
+    assert_eq!(dec.format("%.1x"), "%.1x");
+    assert_eq!(
+        dec.try_format("%.1"),
+        Err(FormatError::UnknownSpecifier { specifier: '.', position: 0 })
+    );
+}
+
+/// ✅ Test `to_packed` layout and round trip
+#[test]
+fn test_packed_round_trip() {
+    let noon = DecimalTime::new(2025, 73, 0.5);
+    // 2025 | 073 | 43200000000
+    assert_eq!(noon.to_packed(), 202_507_343_200_000_000);
+    assert_eq!(DecimalTime::from_packed(noon.to_packed()), Some(noon));
+
+    for dec in [DecimalTime::min_value(), DecimalTime::max_value(), DecimalTime::new(-4, 366, 0.75)] {
+        assert_eq!(DecimalTime::from_packed(dec.to_packed()), Some(dec));
+    }
+}
+
+/// ✅ Test packed values sort like `Ord`, including negative years
+#[test]
+fn test_packed_ordering() {
+    let times = [
+        DecimalTime::new(-2, 365, 0.9),
+        DecimalTime::new(-1, 1, 0.0),
+        DecimalTime::new(-1, 365, 0.5),
+        DecimalTime::new(0, 1, 0.1),
+        DecimalTime::new(2025, 73, 0.5),
+        DecimalTime::new(2025, 73, 0.500001),
+        DecimalTime::new(2025, 74, 0.0),
+    ];
+
+    for pair in times.windows(2) {
+        assert!(pair[0] < pair[1]);
+        assert!(pair[0].to_packed() < pair[1].to_packed());
+    }
+}
+
+/// ❌ Test `from_packed` rejects out-of-range fields
+#[test]
+fn test_from_packed_invalid() {
+    let packed = |year: i128, day: i128, micros: i128| year * 100_000_000_000_000 + day * 100_000_000_000 + micros;
+
+    assert_eq!(DecimalTime::from_packed(packed(2025, 366, 0)), None);
+    assert_eq!(DecimalTime::from_packed(packed(2025, 0, 0)), None);
+    assert_eq!(DecimalTime::from_packed(packed(2025, 73, 86_400_000_000)), None);
+    assert_eq!(DecimalTime::from_packed(i128::MAX), None);
+}
+
+/// ✅ Test `strftime_date` delegates to chrono and `%c` combines with `%f`
+#[test]
+fn test_strftime_date() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+
+    assert_eq!(dec.strftime_date("%A %e %B %Y"), Ok("Friday 14 March 2025".to_string()));
+    assert_eq!(dec.strftime_date("%Y-%m-%dT%H:%M"), Ok("2025-03-14T12:00".to_string()));
+    assert_eq!(dec.format("%c%f"), "2025-03-14.5");
+    assert_eq!(dec.try_format("%c (%Y.%d)"), Ok("2025-03-14 (2025.073)".to_string()));
+}
+
+/// ❌ Test `strftime_date` rejects a format chrono cannot parse
+#[test]
+fn test_strftime_date_invalid() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!(dec.strftime_date("%Q"), Err(FormatError::InvalidStrftime));
+}
+
+/// ✅ Test `is_valid` accepts in-range values
+#[test]
+fn test_is_valid() {
+    assert!(DecimalTime::new(2025, 73, 0.5).is_valid());
+    assert!(DecimalTime::new(2024, 366, 0.0).is_valid());
+    assert!(DecimalTime::min_value().is_valid());
+    assert!(DecimalTime::max_value().is_valid());
+}
+
+/// ❌ Test `is_valid` rejects each kind of out-of-range field
+#[test]
+fn test_is_valid_invalid() {
+    assert!(!DecimalTime { year: 2025, day_of_year: 366, decimal_day: 0.5 }.is_valid());
+    assert!(!DecimalTime { year: 2025, day_of_year: 0, decimal_day: 0.5 }.is_valid());
+    assert!(!DecimalTime { year: 2025, day_of_year: 73, decimal_day: 1.0 }.is_valid());
+    assert!(!DecimalTime { year: 2025, day_of_year: 73, decimal_day: f64::NAN }.is_valid());
+    assert!(!DecimalTime { year: 300_000, day_of_year: 1, decimal_day: 0.0 }.is_valid());
+    assert!(!DecimalTime { year: -300_000, day_of_year: 1, decimal_day: 0.0 }.is_valid());
+}
+
+/// ✅ Test `quarter` and `half` follow the calendar months
+#[test]
+fn test_quarter_and_half() {
+    let day = |year, day_of_year| DecimalTime::new(year, day_of_year, 0.5);
+
+    assert_eq!(day(2025, 1).quarter(), 1);
+    assert_eq!(day(2025, 90).quarter(), 1); // Mar 31
+    assert_eq!(day(2025, 91).quarter(), 2); // Apr 1
+    assert_eq!(day(2025, 181).quarter(), 2); // Jun 30
+    assert_eq!(day(2025, 273).quarter(), 3); // Sep 30
+    assert_eq!(day(2025, 274).quarter(), 4); // Oct 1
+    assert_eq!(day(2025, 365).quarter(), 4);
+
+    // Leap years shift the boundaries by Feb 29
+    assert_eq!(day(2024, 91).quarter(), 1); // Mar 31
+    assert_eq!(day(2024, 92).quarter(), 2); // Apr 1
+    assert_eq!(day(2024, 366).quarter(), 4);
+
+    assert_eq!(day(2025, 181).half(), 1);
+    assert_eq!(day(2025, 182).half(), 2);
+    assert_eq!(day(2024, 182).half(), 1);
+}
+
+/// ✅ Test `from_decimal_year` respects leap years
+#[test]
+fn test_from_decimal_year() {
+    // 0.5 * 366 days = 183 days into 2024
+    assert_eq!(DecimalTime::from_decimal_year(2024.5), Some(DecimalTime::new(2024, 184, 0.0)));
+    // 0.5 * 365 days = 182.5 days into 2025
+    assert_eq!(DecimalTime::from_decimal_year(2025.5), Some(DecimalTime::new(2025, 183, 0.5)));
+    assert_eq!(DecimalTime::from_decimal_year(-0.75), Some(DecimalTime::new(-1, 92, 0.25)));
+}
+
+/// ✅ Test `to_decimal_year` round trips in leap and non-leap years
+#[test]
+fn test_decimal_year_round_trip() {
+    for dec in [DecimalTime::new(2024, 184, 0.0), DecimalTime::new(2025, 183, 0.5), DecimalTime::new(2024, 366, 0.75)] {
+        let round_trip = DecimalTime::from_decimal_year(dec.to_decimal_year()).unwrap();
+        assert!(round_trip.approx_eq(&dec, 1e-6), "{:?} came back as {:?}", dec, round_trip);
+    }
+    assert_eq!(DecimalTime::new(2025, 183, 0.5).to_decimal_year(), 2025.5);
+}
+
+/// ❌ Test `from_decimal_year` and `from_fraction_of_year` reject non-finite and out-of-range years
+#[test]
+fn test_from_decimal_year_invalid() {
+    assert_eq!(DecimalTime::from_decimal_year(f64::NAN), None);
+    assert_eq!(DecimalTime::from_decimal_year(f64::NEG_INFINITY), None);
+    assert_eq!(DecimalTime::from_decimal_year(1e12), None);
+    assert_eq!(DecimalTime::from_decimal_year(300_000.5), None);
+    assert_eq!(DecimalTime::from_decimal_year(-262_144.5), None);
+    assert!(DecimalTime::from_decimal_year(DecimalTime::MAX_YEAR as f64 + 0.5).is_some());
+
+    assert_eq!(DecimalTime::from_fraction_of_year(300_000, 0.5), None);
+    assert_eq!(DecimalTime::from_fraction_of_year(i32::MIN, 0.0), None);
+}
+
+/// ✅ Test `format_into` writes into a reused buffer and matches `format`
+#[test]
+fn test_format_into() {
+    let fmt = "%'Y-%d%f %T %p %w %c %%";
+    let mut buffer = String::new();
+
+    for dec in [DecimalTime::new(2025, 73, 0.5), DecimalTime::new(-12_345, 1, 0.0), DecimalTime::new(2024, 366, 0.999)] {
+        buffer.clear();
+        dec.format_into(&mut buffer, fmt).unwrap();
+        assert_eq!(buffer, dec.format(fmt));
+    }
+
+    buffer.clear();
+    DecimalTime::new(2025, 73, 0.5).format_into(&mut buffer, "%Y.%d%f").unwrap();
+    assert_eq!(buffer, "2025.073.5");
+}
+
+/// ❌ Test `format_into` reports a failing writer
+#[test]
+fn test_format_into_writer_error() {
+    struct Full;
+    impl core::fmt::Write for Full {
+        fn write_str(&mut self, _: &str) -> core::fmt::Result {
+            Err(core::fmt::Error)
+        }
+    }
+
+    assert!(DecimalTime::new(2025, 73, 0.5).format_into(&mut Full, "%Y").is_err());
+}
+
+/// ✅ Test the `TryFrom` conversions in both directions
+#[test]
+fn test_try_from_conversions() {
+    let ndt = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let dec = DecimalTime::new(2025, 73, 0.5);
+
+    assert_eq!(DecimalTime::try_from(ndt), Ok(dec));
+    assert_eq!(DecimalTime::try_from(ndt.and_utc()), Ok(dec));
+    assert_eq!(chrono::NaiveDateTime::try_from(dec), Ok(ndt));
+
+    let converted: Result<DecimalTime, _> = ndt.try_into();
+    assert_eq!(converted, Ok(dec));
+}
+
+/// ❌ Test the `TryFrom` conversions report invalid values
+#[test]
+fn test_try_from_conversions_invalid() {
+    let leap_second = NaiveDate::from_ymd_opt(2016, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 1_500).unwrap();
+    assert!(matches!(
+        DecimalTime::try_from(leap_second),
+        Err(DecimalTimeError::DecimalDayOutOfRange(_))
+    ));
+
+    assert_eq!(
+        chrono::NaiveDateTime::try_from(DecimalTime { year: 2025, day_of_year: 366, decimal_day: 0.5 }),
+        Err(DecimalTimeError::DayOfYearOutOfRange { year: 2025, day_of_year: 366 })
+    );
+    assert_eq!(
+        chrono::NaiveDateTime::try_from(DecimalTime { year: 2025, day_of_year: 73, decimal_day: 1.5 }),
+        Err(DecimalTimeError::DecimalDayOutOfRange(1.5))
+    );
+}
+
+/// ✅ Test `shift_offset` from UTC to UTC+13 rolls into the next day and year
+#[test]
+fn test_shift_offset() {
+    let utc = chrono::FixedOffset::east_opt(0).unwrap();
+    let plus_13 = chrono::FixedOffset::east_opt(13 * 3600).unwrap();
+
+    // 00:00 UTC is 13:00 at UTC+13, the same day
+    assert!(DecimalTime::new(2025, 73, 0.0)
+        .shift_offset(utc, plus_13)
+        .approx_eq(&DecimalTime::new(2025, 73, 13.0 / 24.0), 1e-9));
+    // 12:00 UTC on Dec 31 is 01:00 on Jan 1 at UTC+13
+    assert!(DecimalTime::new(2025, 365, 0.5)
+        .shift_offset(utc, plus_13)
+        .approx_eq(&DecimalTime::new(2026, 1, 1.0 / 24.0), 1e-9));
+    // and back again
+    assert!(DecimalTime::new(2026, 1, 1.0 / 24.0)
+        .shift_offset(plus_13, utc)
+        .approx_eq(&DecimalTime::new(2025, 365, 0.5), 1e-9));
+}
+
+/// ❌ Test `shift_offset` past the representable range panics
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_shift_offset_out_of_range() {
+    let utc = chrono::FixedOffset::east_opt(0).unwrap();
+    let plus_13 = chrono::FixedOffset::east_opt(13 * 3600).unwrap();
+    DecimalTime::max_value().shift_offset(utc, plus_13);
+}
+
+/// ✅ Test `display_with` options
+#[test]
+fn test_display_with() {
+    use decimal_time::DisplayOptions;
+
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!(dec.display_with(DisplayOptions::default()).to_string(), "2025-073.500000");
+    assert_eq!(
+        format!("{}", dec.display_with(DisplayOptions { fraction_digits: 3, separator: '-', ..Default::default() })),
+        "2025-073.500"
+    );
+    assert_eq!(
+        dec.display_with(DisplayOptions { fraction_digits: 1, pad_day: false, separator: '.' }).to_string(),
+        "2025.73.5"
+    );
+    assert_eq!(dec.display_with(DisplayOptions { fraction_digits: 0, ..Default::default() }).to_string(), "2025-073");
+}
+
+/// ✅ Test `display_with` carries a fraction that rounds up into the next day
+#[test]
+fn test_display_with_rounding_carry() {
+    use decimal_time::DisplayOptions;
+
+    let opts = DisplayOptions { fraction_digits: 2, ..Default::default() };
+    assert_eq!(DecimalTime::new(2025, 73, 0.1234).display_with(opts).to_string(), "2025-073.12");
+    assert_eq!(DecimalTime::new(2024, 366, 0.999).display_with(opts).to_string(), "2025-001.00");
+}
+
+/// ✅ Test `display_with` caps fraction digits at the 17 an `f64` holds
+#[test]
+fn test_display_with_large_fraction_digits() {
+    use decimal_time::DisplayOptions;
+
+    for fraction_digits in [18, 400, 70_000, u32::MAX] {
+        let opts = DisplayOptions { fraction_digits, ..Default::default() };
+        assert_eq!(DecimalTime::new(2025, 73, 0.5).display_with(opts).to_string(), "2025-073.50000000000000000");
+        assert_eq!(DecimalTime::new(2025, 73, 0.0).display_with(opts).to_string(), "2025-073.00000000000000000");
+    }
+
+    let opts = DisplayOptions { fraction_digits: 3, ..Default::default() };
+    assert_eq!(DecimalTime::try_new(2025, 73, -0.0).unwrap().display_with(opts).to_string(), "2025-073.000");
+}
+
+/// ✅ Test clock hand angles, clockwise from midnight at the top
+#[test]
+fn test_hand_angles() {
+    let dec = DecimalTime::new(2025, 73, 0.25);
+    assert_eq!(dec.hand_angle_degrees(), 90.0);
+    assert_eq!(dec.decimal_hour_angle_degrees(), 90.0);
+    assert!((dec.decimal_minute_angle_degrees() - 180.0).abs() < 1e-9);
+
+    let midnight = DecimalTime::new(2025, 73, 0.0);
+    assert_eq!(midnight.hand_angle_degrees(), 0.0);
+    assert_eq!(midnight.decimal_minute_angle_degrees(), 0.0);
+
+    // 7 decimal hours and 50 decimal minutes
+    let evening = DecimalTime::new(2025, 73, 0.75);
+    assert_eq!(evening.hand_angle_degrees(), 270.0);
+    assert!((evening.decimal_minute_angle_degrees() - 180.0).abs() < 1e-9);
+}
+
+/// ✅ Test `to_bytes` layout and round trip
+#[test]
+fn test_bytes_round_trip() {
+    let noon = DecimalTime::new(2025, 73, 0.5);
+    let bytes = noon.to_bytes();
+    assert_eq!(&bytes[4..8], &73u32.to_be_bytes());
+    assert_eq!(&bytes[8..], &43_200_000_000u64.to_be_bytes());
+
+    for dec in [noon, DecimalTime::min_value(), DecimalTime::max_value(), DecimalTime::new(-4, 366, 0.125)] {
+        assert_eq!(DecimalTime::from_bytes(&dec.to_bytes()), Some(dec));
+    }
+}
+
+/// ✅ Test the raw bytes sort chronologically
+#[test]
+fn test_bytes_ordering() {
+    let times = [
+        DecimalTime::min_value(),
+        DecimalTime::new(-1, 365, 0.9),
+        DecimalTime::new(0, 1, 0.0),
+        DecimalTime::new(2025, 73, 0.5),
+        DecimalTime::new(2025, 73, 0.500001),
+        DecimalTime::new(2025, 74, 0.0),
+        DecimalTime::max_value(),
+    ];
+
+    for pair in times.windows(2) {
+        assert!(pair[0].to_bytes() < pair[1].to_bytes(), "{:?} !< {:?}", pair[0], pair[1]);
+    }
+}
+
+/// ❌ Test `from_bytes` rejects out-of-range fields
+#[test]
+fn test_from_bytes_invalid() {
+    let mut bytes = DecimalTime::new(2025, 73, 0.5).to_bytes();
+    bytes[4..8].copy_from_slice(&366u32.to_be_bytes());
+    assert_eq!(DecimalTime::from_bytes(&bytes), None);
+
+    let mut bytes = DecimalTime::new(2025, 73, 0.5).to_bytes();
+    bytes[8..].copy_from_slice(&86_400_000_000u64.to_be_bytes());
+    assert_eq!(DecimalTime::from_bytes(&bytes), None);
+}
+
+/// ✅ Test `with_time_of_day` converts a duration into the fraction
+#[test]
+fn test_with_time_of_day() {
+    let dec = DecimalTime::new(2025, 73, 0.9);
+
+    assert_eq!(dec.with_time_of_day(chrono::Duration::hours(6)), Ok(DecimalTime::new(2025, 73, 0.25)));
+    assert_eq!(
+        dec.with_time_of_day(chrono::Duration::hours(6) + chrono::Duration::minutes(30)),
+        Ok(DecimalTime::new(2025, 73, 6.5 / 24.0))
+    );
+    assert_eq!(dec.with_time_of_day(chrono::Duration::zero()), Ok(DecimalTime::new(2025, 73, 0.0)));
+}
+
+/// ❌ Test `with_time_of_day` rejects negative and full-day durations
+#[test]
+fn test_with_time_of_day_invalid() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+
+    assert_eq!(
+        dec.with_time_of_day(chrono::Duration::hours(24)),
+        Err(DecimalTimeError::DecimalDayOutOfRange(1.0))
+    );
+    assert_eq!(
+        dec.with_time_of_day(chrono::Duration::hours(-6)),
+        Err(DecimalTimeError::DecimalDayOutOfRange(-0.25))
+    );
+    assert!(dec.with_time_of_day(chrono::Duration::MAX).is_err());
+}
+
+/// ✅ Test `decimal_hour_marks` and `decimal_minute_marks`
+#[test]
+fn test_decimal_marks() {
+    let hours = DecimalTime::decimal_hour_marks(2024, 366).unwrap();
+    assert_eq!(hours[0], DecimalTime::new(2024, 366, 0.0));
+    assert_eq!(hours[5], DecimalTime::new(2024, 366, 0.5));
+    assert_eq!(hours[9], DecimalTime::new(2024, 366, 0.9));
+
+    let minutes = DecimalTime::decimal_minute_marks(2025, 73, 7).unwrap();
+    assert_eq!(minutes[0], DecimalTime::new(2025, 73, 0.7));
+    assert_eq!(minutes[50], DecimalTime::new(2025, 73, 0.75));
+    assert_eq!(minutes[99], DecimalTime::new(2025, 73, 0.799));
+    assert!(DecimalTime::is_sorted_ascending(&minutes));
+}
+
+/// ❌ Test the mark generators validate the day and hour
+#[test]
+fn test_decimal_marks_invalid() {
+    assert_eq!(
+        DecimalTime::decimal_hour_marks(2025, 366),
+        Err(DecimalTimeError::DayOfYearOutOfRange { year: 2025, day_of_year: 366 })
+    );
+    assert_eq!(
+        DecimalTime::decimal_minute_marks(2025, 73, 10).err(),
+        Some(DecimalTimeError::ComponentOutOfRange { component: "hour", value: 10.0 })
+    );
+}
+
+/// ✅ Test the boundary years `MIN_YEAR` / `MAX_YEAR` are accepted
+#[test]
+fn test_year_range_boundaries() {
+    assert_eq!(DecimalTime::MIN_YEAR, NaiveDate::MIN.year());
+    assert_eq!(DecimalTime::MAX_YEAR, NaiveDate::MAX.year());
+
+    let first = DecimalTime::midnight(DecimalTime::MIN_YEAR, 1).unwrap();
+    assert_eq!(first.to_naive_datetime(), NaiveDate::MIN.and_hms_opt(0, 0, 0).unwrap());
+    let last = DecimalTime::noon(DecimalTime::MAX_YEAR, 365).unwrap();
+    assert_eq!(last.to_naive_datetime(), NaiveDate::MAX.and_hms_opt(12, 0, 0).unwrap());
+    assert!(DecimalTime::new(DecimalTime::MAX_YEAR, 365, 0.5).is_valid());
+}
+
+/// ❌ Test years just outside the supported range are rejected
+#[test]
+fn test_year_out_of_range() {
+    assert_eq!(
+        DecimalTime::midnight(DecimalTime::MIN_YEAR - 1, 1),
+        Err(DecimalTimeError::YearOutOfRange(-262_144))
+    );
+    assert_eq!(
+        DecimalTime::new(2025, 73, 0.5).with_year(DecimalTime::MAX_YEAR + 1),
+        Err(DecimalTimeError::YearOutOfRange(262_143))
+    );
+    assert!(!DecimalTime::new_unchecked(i32::MAX, 1, 0.0).is_valid());
+    assert_eq!(
+        chrono::NaiveDateTime::try_from(DecimalTime::new_unchecked(i32::MIN, 1, 0.0)),
+        Err(DecimalTimeError::YearOutOfRange(i32::MIN))
+    );
+}
+
+/// ❌ Test `new` panics on a year outside the supported range
+#[test]
+#[should_panic(expected = "`year` must be in")]
+fn test_new_year_out_of_range() {
+    DecimalTime::new(DecimalTime::MAX_YEAR + 1, 1, 0.0);
+}
+
+/// ✅ Test `humanize_since` picks a unit and tense from the signed duration
+#[test]
+fn test_humanize_since() {
+    let reference = DecimalTime::new(2025, 73, 0.5);
+
+    assert_eq!(reference.humanize_since(&reference), "now");
+    assert_eq!(DecimalTime::new(2025, 73, 0.500_000_1).humanize_since(&reference), "now");
+    assert_eq!(DecimalTime::new(2025, 73, 0.496).humanize_since(&reference), "now");
+    assert_eq!(DecimalTime::new(2025, 73, 0.494).humanize_since(&reference), "0.01 decimal days ago");
+    assert_eq!(DecimalTime::new(2025, 73, 0.25).humanize_since(&reference), "0.25 decimal days ago");
+    assert_eq!(DecimalTime::new(2025, 75, 0.0).humanize_since(&reference), "in 1.50 decimal days");
+    assert_eq!(DecimalTime::new(2025, 83, 0.5).humanize_since(&reference), "in 1 décade");
+    assert_eq!(DecimalTime::new(2025, 43, 0.5).humanize_since(&reference), "3 décades ago");
+    assert_eq!(DecimalTime::new(2027, 73, 0.5).humanize_since(&reference), "in 2.0 years");
+    assert_eq!(DecimalTime::new(2024, 73, 0.5).humanize_since(&reference), "1.0 years ago");
+}
+
+/// ✅ Test snapping to the decimal-minute and decimal-second grid
+#[test]
+fn test_snap_to_decimal_units() {
+    let dec = DecimalTime::new(2025, 73, 0.123_456);
+    assert_eq!(dec.snap_to_decimal_minute(), DecimalTime::new(2025, 73, 0.123));
+    assert_eq!(dec.snap_to_decimal_second(), DecimalTime::new(2025, 73, 0.123_46));
+
+    assert_eq!(DecimalTime::new(2025, 73, 0.9999).snap_to_decimal_minute(), DecimalTime::new(2025, 74, 0.0));
+    assert_eq!(DecimalTime::new(2025, 365, 0.999_999).snap_to_decimal_second(), DecimalTime::new(2026, 1, 0.0));
+    assert_eq!(DecimalTime::new(2025, 73, 0.9999).snap_to_decimal_second(), DecimalTime::new(2025, 73, 0.9999));
+}
+
+/// ✅ Test `is_before` / `is_after` against `DateTime<Utc>`
+#[test]
+fn test_is_before_is_after() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    let noon = Utc.with_ymd_and_hms(2025, 3, 14, 12, 0, 0).unwrap();
+    let later = Utc.with_ymd_and_hms(2025, 3, 14, 12, 0, 1).unwrap();
+
+    assert!(dec.is_before(later));
+    assert!(!dec.is_after(later));
+    assert!(!dec.is_before(noon));
+    assert!(!dec.is_after(noon));
+    assert!(DecimalTime::new(2025, 74, 0.0).is_after(later));
+}
+
+/// ❌ Test an invalid date is neither before nor after anything
+#[test]
+fn test_is_before_is_after_invalid() {
+    let invalid = DecimalTime::new_unchecked(2025, 366, 0.5);
+    let dt = Utc.with_ymd_and_hms(2025, 3, 14, 12, 0, 0).unwrap();
+
+    assert!(!invalid.is_before(dt));
+    assert!(!invalid.is_after(dt));
+}
+
+/// ✅ Test `earliest` / `latest`
+#[test]
+fn test_earliest_latest() {
+    let a = DecimalTime::new(2025, 73, 0.5);
+    let b = DecimalTime::new(2024, 200, 0.9);
+
+    assert_eq!(DecimalTime::earliest(a, b), b);
+    assert_eq!(DecimalTime::latest(a, b), a);
+    assert_eq!(DecimalTime::earliest(a, a), a);
+}
+
+/// ✅ Test `try_new` accepts valid input
+#[test]
+fn test_try_new_valid() {
+    assert_eq!(DecimalTime::try_new(2025, 100, 0.25), Ok(DecimalTime::new(2025, 100, 0.25)));
+    assert!(DecimalTime::try_new(2024, 366, 0.0).is_ok());
+}
+
+/// ❌ Test `try_new` reports which field is out of range
+#[test]
+fn test_try_new_invalid() {
+    assert_eq!(DecimalTime::try_new(2025, 50, 1.0), Err(DecimalTimeError::DecimalDayOutOfRange(1.0)));
+    assert!(matches!(
+        DecimalTime::try_new(2025, 50, f64::NAN),
+        Err(DecimalTimeError::DecimalDayOutOfRange(v)) if v.is_nan()
+    ));
+    assert_eq!(
+        DecimalTime::try_new(2025, 0, 0.5),
+        Err(DecimalTimeError::DayOfYearOutOfRange { year: 2025, day_of_year: 0 })
+    );
+    assert_eq!(
+        DecimalTime::try_new(2025, 366, 0.5),
+        Err(DecimalTimeError::DayOfYearOutOfRange { year: 2025, day_of_year: 366 })
+    );
+    assert_eq!(DecimalTime::try_new(300_000, 1, 0.5), Err(DecimalTimeError::YearOutOfRange(300_000)));
+}
+
+/// ✅ Test `Display` / `FromStr` round-trip the canonical form
+#[test]
+fn test_display_from_str_canonical() {
+    let dec = DecimalTime::new(2025, 100, 0.5);
+    assert_eq!(dec.to_string(), "2025-100.5");
+    assert_eq!("2025-100.5".parse::<DecimalTime>(), Ok(dec));
+    assert_eq!("2025-100.500000".parse::<DecimalTime>(), Ok(dec));
+    assert_eq!(DecimalTime::new(2025, 100, 0.0).to_string(), "2025-100");
+
+    let early = DecimalTime::new(-44, 75, 0.123_456);
+    assert_eq!(early.to_string(), "-44-075.123456");
+    assert_eq!(early.to_string().parse::<DecimalTime>(), Ok(early));
+
+    // Lossless: a microsecond-precise chrono value survives the round trip
+    let dt = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap().and_hms_micro_opt(15, 9, 26, 535_897).unwrap();
+    let precise = DecimalTime::from_naive_datetime(dt);
+    let reparsed: DecimalTime = precise.to_string().parse().unwrap();
+    assert_eq!(reparsed, precise);
+    assert_eq!(reparsed.to_naive_datetime(), dt);
+}
+
+/// ❌ Test `FromStr` rejects malformed and out-of-range input
+#[test]
+fn test_from_str_invalid() {
+    assert!(matches!("2025/100.5".parse::<DecimalTime>(), Err(ParseError::Malformed(_))));
+    assert!(matches!("2025-100.5x".parse::<DecimalTime>(), Err(ParseError::Malformed(_))));
+    assert_eq!(
+        "2025-366.500000".parse::<DecimalTime>(),
+        Err(ParseError::OutOfRange(DecimalTimeError::DayOfYearOutOfRange { year: 2025, day_of_year: 366 }))
+    );
+}
+
+/// ✅ Test `parse_from_format` reads back every `format` token
+#[test]
+fn test_parse_from_format_round_trip() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    for fmt in ["%Y.%d%f", "%'Y-%j%f", "%c %T", "%w %c %R", "%Y-%d %p", "%Y-%d %.2p", "%Y-%d 100%% %r"] {
+        assert_eq!(DecimalTime::parse_from_format(&dec.format(fmt), fmt), Ok(dec), "{}", fmt);
+    }
+    assert_eq!(
+        DecimalTime::parse_from_format("1,234-001", "%'Y-%d"),
+        Ok(DecimalTime::new(1234, 1, 0.0))
+    );
+    assert_eq!(
+        DecimalTime::parse_from_format("-0044-03-15 06:00", "%c %R"),
+        Ok(DecimalTime::new(-44, 75, 0.25))
+    );
+}
+
+/// ❌ Test `parse_from_format` reports where parsing failed
+#[test]
+fn test_parse_from_format_errors() {
+    let malformed = |input: &str, fmt: &str| match DecimalTime::parse_from_format(input, fmt) {
+        Err(ParseError::Malformed(reason)) => reason,
+        other => panic!("expected Malformed, got {:?}", other),
+    };
+
+    assert_eq!(malformed("2025:073.5", "%Y.%d%f"), "expected '.' at position 4");
+    assert_eq!(malformed("2025.x", "%Y.%d"), "invalid day_of_year \"\" at position 5");
+    assert_eq!(malformed("2025-073 x", "%Y-%d %q"), "unknown format specifier %q at position 6");
+    assert_eq!(malformed("2025-073", "%Y-%d%"), "format string ends with a lone %");
+    assert_eq!(malformed("2025-02-30", "%c"), "invalid YYYY-MM-DD \"2025-02-30\" at position 0");
+    assert_eq!(malformed("Sat 2025-03-14", "%w %c"), "weekday Sat does not match the date at position 0");
+    assert_eq!(malformed("2025-073 50", "%Y-%d %p"), "expected '%' at position 11");
+    assert_eq!(malformed("2025-073 25:00", "%Y-%d %R"), "invalid HH:MM \"25:00\" at position 9");
+
+    // The lenient parser matches unknown sequences literally instead
+    assert_eq!(DecimalTime::parse_from_str("2025-073 %q", "%Y-%d %q"), Ok(DecimalTime::new(2025, 73, 0.0)));
+}
+
+/// ✅ Test zero-padding width and fixed fraction precision tokens
+#[test]
+fn test_format_width_and_precision() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!(dec.format("%Y.%03j%.3f"), "2025.073.500");
+    assert_eq!(dec.format("%05d|%06Y|%.1f"), "00073|002025|.5");
+    assert_eq!(DecimalTime::new(-44, 5, 0.0).format("%05Y-%02j"), "-0044-05");
+    assert_eq!(DecimalTime::new(2025, 73, 0.123_456).format("%.4f"), ".1235");
+    assert_eq!(DecimalTime::new(2025, 73, 0.999_99).format("%.3f"), ".999");
+    assert_eq!(dec.format("%.99f").len(), 100);
+    assert!(dec.format("%.99f").starts_with(".5000"));
+
+    for fmt in ["%Y.%03j%.3f", "%06Y-%05d%.6f"] {
+        assert_eq!(DecimalTime::parse_from_format(&dec.format(fmt), fmt), Ok(dec), "{}", fmt);
+    }
+}
+
+/// ❌ Test malformed width and precision tokens are unknown specifiers
+#[test]
+fn test_format_width_and_precision_invalid() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!(dec.try_format("%0j"), Err(FormatError::UnknownSpecifier { specifier: '0', position: 0 }));
+    assert_eq!(dec.try_format("%03r"), Err(FormatError::UnknownSpecifier { specifier: '0', position: 0 }));
+    assert_eq!(dec.try_format("%.f"), Err(FormatError::UnknownSpecifier { specifier: '.', position: 0 }));
+    assert_eq!(dec.format("%03q %.x"), "%03q %.x");
+}
+
+/// ❌ Test widths and precisions above 99 are unknown specifiers instead of a panic
+#[test]
+fn test_format_width_and_precision_too_large() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!(dec.try_format("%0100000Y"), Err(FormatError::UnknownSpecifier { specifier: '0', position: 0 }));
+    assert_eq!(dec.try_format("%.70000f"), Err(FormatError::UnknownSpecifier { specifier: '.', position: 0 }));
+    assert_eq!(dec.try_format("%.100p"), Err(FormatError::UnknownSpecifier { specifier: '.', position: 0 }));
+    assert_eq!(dec.format("%0100000Y"), "%0100000Y");
+    assert_eq!(dec.format("%.70000f"), "%.70000f");
+    assert_eq!(dec.format("%099Y").len(), 99);
+}
+
+/// ✅ Test a compiled `FormatSpec` matches `format` / `parse_from_format`
+#[test]
+fn test_format_spec() {
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    for fmt in ["%Y.%d%f", "%'Y-%03j %T %w", "%c %.1p 100%%", "%06Y/%.4f"] {
+        let spec = FormatSpec::parse(fmt).unwrap();
+        assert_eq!(spec.format(&dec), dec.format(fmt), "{}", fmt);
+        assert_eq!(spec.parse_str(&spec.format(&dec)), DecimalTime::parse_from_format(&dec.format(fmt), fmt));
+    }
+
+    let spec: FormatSpec = "%Y-%d%f".parse().unwrap();
+    let mut buffer = String::new();
+    for day in [1, 73, 365] {
+        buffer.clear();
+        spec.format_into(&mut buffer, &DecimalTime::new(2025, day, 0.25)).unwrap();
+        assert_eq!(spec.parse_str(&buffer), Ok(DecimalTime::new(2025, day, 0.25)));
+    }
+}
+
+/// ❌ Test `FormatSpec` rejects bad templates up front and bad input on parse
+#[test]
+fn test_format_spec_invalid() {
+    assert_eq!(FormatSpec::parse("%Y-%q"), Err(FormatError::UnknownSpecifier { specifier: 'q', position: 3 }));
+    assert_eq!(FormatSpec::parse("%Y %e"), Err(FormatError::UnknownSpecifier { specifier: 'e', position: 3 }));
+    assert_eq!(FormatSpec::parse("%Y%"), Err(FormatError::TrailingPercent));
+
+    let spec = FormatSpec::parse("%Y.%d%f").unwrap();
+    assert_eq!(
+        spec.parse_str("2025:073.5"),
+        Err(ParseError::Malformed("expected '.' at position 4".to_string()))
+    );
+}
+
+/// ✅ Test `format_lazy` writes the same text as `format`
+#[test]
+fn test_format_lazy() {
+    use std::fmt::Write;
+
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    for fmt in ["%Y.%d%f", "%'Y %T %w %c", "%.2p %q %%"] {
+        assert_eq!(dec.format_lazy(fmt).to_string(), dec.format(fmt), "{}", fmt);
+    }
+
+    let mut line = String::new();
+    write!(line, "[{}] event", dec.format_lazy("%Y-%03j")).unwrap();
+    assert_eq!(line, "[2025-073] event");
+}
+
+/// ✅ Test a `-0.0` fraction equals and sorts with midnight
+#[test]
+fn test_negative_zero_fraction_is_midnight() {
+    let negative = DecimalTime::try_new(2025, 1, -0.0).unwrap();
+    let midnight = DecimalTime::new(2025, 1, 0.0);
+
+    assert_eq!(negative, midnight);
+    assert_eq!(negative.cmp(&midnight), std::cmp::Ordering::Equal);
+    assert_eq!(negative.sort_key(), midnight.sort_key());
+    assert!(negative < DecimalTime::new(2025, 1, f64::MIN_POSITIVE));
+    assert!(DecimalTime::new(2024, 366, 0.9) < negative);
+}
+
+/// ✅ Test a `-0.0` fraction displays as midnight and round-trips through `FromStr`
+#[test]
+fn test_negative_zero_fraction_display_round_trip() {
+    let negative = DecimalTime::try_new(2025, 100, -0.0).unwrap();
+
+    assert_eq!(negative.to_string(), "2025-100");
+    assert_eq!(negative.to_string().parse::<DecimalTime>(), Ok(negative));
+    assert_eq!(negative.format("%Y-%d%.3f"), "2025-100.000");
+}
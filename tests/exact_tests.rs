@@ -0,0 +1,55 @@
+use chrono::NaiveDate;
+use decimal_time::{DecimalTime, DecimalTimeError, DecimalTimeExact};
+
+/// ✅ Test chrono round trips are lossless at microsecond precision
+#[test]
+fn test_exact_round_trip() {
+    let dt = NaiveDate::from_ymd_opt(2024, 12, 31)
+        .unwrap()
+        .and_hms_micro_opt(23, 59, 59, 999_999)
+        .unwrap();
+    let exact = DecimalTimeExact::from_naive_datetime(dt);
+
+    assert_eq!(exact.year(), 2024);
+    assert_eq!(exact.day_of_year(), 366);
+    assert_eq!(exact.microseconds_into_day(), 86_399_999_999);
+    assert_eq!(exact.to_naive_datetime(), dt);
+}
+
+/// ✅ Test `decimal_day` and the conversions to and from `DecimalTime`
+#[test]
+fn test_exact_decimal_day() {
+    let exact = DecimalTimeExact::new(2025, 73, 43_200_000_000).unwrap();
+
+    assert_eq!(exact.decimal_day(), 0.5);
+    assert_eq!(exact.to_decimal_time(), DecimalTime::new(2025, 73, 0.5));
+    assert_eq!(DecimalTimeExact::from_decimal_time(&DecimalTime::new(2025, 73, 0.5)), Some(exact));
+    assert_eq!(
+        DecimalTimeExact::from_decimal_time(&DecimalTime::new(2025, 365, 0.9999999999999)),
+        Some(DecimalTimeExact::new(2026, 1, 0).unwrap())
+    );
+}
+
+/// ✅ Test ordering compares the stored microseconds exactly
+#[test]
+fn test_exact_ordering() {
+    let a = DecimalTimeExact::new(2025, 73, 1).unwrap();
+    let b = DecimalTimeExact::new(2025, 73, 2).unwrap();
+
+    assert!(a < b);
+    assert!(b < DecimalTimeExact::new(2025, 74, 0).unwrap());
+}
+
+/// ❌ Test invalid fields are rejected
+#[test]
+fn test_exact_invalid() {
+    assert_eq!(
+        DecimalTimeExact::new(2025, 73, 86_400_000_000),
+        Err(DecimalTimeError::DecimalDayOutOfRange(1.0))
+    );
+    assert_eq!(
+        DecimalTimeExact::new(2025, 366, 0),
+        Err(DecimalTimeError::DayOfYearOutOfRange { year: 2025, day_of_year: 366 })
+    );
+    assert_eq!(DecimalTimeExact::from_decimal_time(&DecimalTime::new_unchecked(2025, 366, 0.5)), None);
+}
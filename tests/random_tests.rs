@@ -0,0 +1,40 @@
+#![cfg(feature = "rand")]
+
+use decimal_time::DecimalTime;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// ✅ Test all samples fall within `[start, end)`
+#[test]
+fn test_sample_uniform_within_range() {
+    let start = DecimalTime::new(2024, 366, 0.5);
+    let end = DecimalTime::new(2025, 2, 0.25);
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+
+    for _ in 0..1_000 {
+        let sample = DecimalTime::sample_uniform(&mut rng, &start, &end);
+        assert!(start <= sample && sample < end, "{:?} out of range", sample);
+    }
+}
+
+/// ✅ Test a fixed seed produces a deterministic value
+#[test]
+fn test_sample_uniform_deterministic() {
+    let start = DecimalTime::new(2025, 1, 0.0);
+    let end = DecimalTime::new(2025, 11, 0.0);
+
+    let a = DecimalTime::sample_uniform(&mut ChaCha8Rng::seed_from_u64(42), &start, &end);
+    let b = DecimalTime::sample_uniform(&mut ChaCha8Rng::seed_from_u64(42), &start, &end);
+    assert_eq!(a, b);
+    assert_eq!((a.year, a.day_of_year), (2025, 7));
+    assert!((a.decimal_day - 0.818_961_923).abs() < 1e-9);
+}
+
+/// ✅ Test an empty range returns `start`
+#[test]
+fn test_sample_uniform_empty_range() {
+    let start = DecimalTime::new(2025, 1, 0.5);
+    let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+    assert_eq!(DecimalTime::sample_uniform(&mut rng, &start, &start), start);
+}
@@ -0,0 +1,304 @@
+use decimal_time::{DecimalDuration, DecimalTime, DecimalTimeRange};
+
+/// ✅ Test `signed_duration_since` in both directions
+#[test]
+fn test_signed_duration_since() {
+    let a = DecimalTime::new(2025, 73, 0.25);
+    let b = DecimalTime::new(2025, 74, 0.5);
+
+    assert_eq!(b.signed_duration_since(&a), DecimalDuration::from_decimal_days(1.25));
+    assert_eq!(a.signed_duration_since(&b), DecimalDuration::from_decimal_days(-1.25));
+}
+
+/// ✅ Test formatting a one-day range
+#[test]
+fn test_range_format_one_day() {
+    let range = DecimalTimeRange::new(DecimalTime::new(2025, 73, 0.5), DecimalTime::new(2025, 74, 0.5));
+
+    assert_eq!(range.duration(), DecimalDuration::from_decimal_days(1.0));
+    assert_eq!(range.format("%Y-%d%f"), "2025-073.5 – 2025-074.5 (1d)");
+}
+
+/// ✅ Test formatting a range with a custom separator
+#[test]
+fn test_range_format_with_separator() {
+    let range = DecimalTimeRange::new(DecimalTime::new(2025, 73, 0.25), DecimalTime::new(2025, 73, 0.5));
+
+    assert_eq!(range.format_with_separator("%Y.%d%f", " .. "), "2025.073.25 .. 2025.073.5 (0.25d)");
+}
+
+/// ✅ Test `DecimalTime + DecimalDuration` carries across the year boundary
+#[test]
+fn test_add_duration() {
+    let start = DecimalTime::new(2024, 366, 0.9375);
+    let end = start + DecimalDuration::from_decimal_days(0.125);
+
+    assert_eq!(end, DecimalTime::new(2025, 1, 0.0625));
+    assert_eq!(end - DecimalDuration::from_decimal_days(0.125), start);
+}
+
+/// ✅ Test `DecimalTime - DecimalTime` yields a signed duration
+#[test]
+fn test_sub_times() {
+    let a = DecimalTime::new(2025, 73, 0.25);
+    let b = DecimalTime::new(2025, 75, 0.5);
+
+    assert_eq!(b - a, DecimalDuration::from_decimal_days(2.25));
+    assert_eq!(a - b, DecimalDuration::from_decimal_days(-2.25));
+}
+
+/// ✅ Test adding past the representable limits saturates
+#[test]
+fn test_add_duration_saturates() {
+    let huge = DecimalDuration::from_decimal_days(1e9);
+
+    assert_eq!(DecimalTime::new(2025, 1, 0.5) + huge, DecimalTime::MAX);
+    assert_eq!(DecimalTime::new(2025, 1, 0.5) - huge, DecimalTime::MIN);
+}
+
+/// ✅ Test an out-of-range start is clamped up to `min_value()`
+#[test]
+fn test_clamped_to_representable_start() {
+    let range = DecimalTimeRange::new(
+        DecimalTime { year: -300_000, day_of_year: 1, decimal_day: 0.5 },
+        DecimalTime::new(2025, 1, 0.5),
+    );
+    let clamped = range.clamped_to_representable();
+
+    assert_eq!(clamped.start, DecimalTime::min_value());
+    assert_eq!(clamped.end, range.end);
+    assert!(clamped.start.to_naive_datetime_opt().is_some());
+}
+
+/// ✅ Test an out-of-range end is clamped down to `max_value()`
+#[test]
+fn test_clamped_to_representable_end() {
+    let range = DecimalTimeRange::new(
+        DecimalTime::new(2025, 1, 0.5),
+        DecimalTime { year: 300_000, day_of_year: 1, decimal_day: 0.5 },
+    );
+    let clamped = range.clamped_to_representable();
+
+    assert_eq!(clamped.start, range.start);
+    assert_eq!(clamped.end, DecimalTime::max_value());
+    assert!(clamped.end.to_naive_datetime_opt().is_some());
+}
+
+/// ✅ Test `find_gaps` reports only the large gap in a series
+#[test]
+fn test_find_gaps() {
+    let times = [
+        DecimalTime::new(2025, 73, 0.1),
+        DecimalTime::new(2025, 73, 0.2),
+        DecimalTime::new(2025, 73, 0.25),
+        DecimalTime::new(2025, 74, 0.5), // large gap before this one
+        DecimalTime::new(2025, 74, 0.55),
+        DecimalTime::new(2025, 74, 0.6),
+    ];
+
+    let gaps = DecimalTime::find_gaps(&times, DecimalDuration::from_decimal_days(0.25));
+    assert_eq!(gaps, vec![(times[2], times[3])]);
+    assert!(DecimalTime::find_gaps(&times, DecimalDuration::from_decimal_days(2.0)).is_empty());
+    assert!(DecimalTime::find_gaps(&[], DecimalDuration::ZERO).is_empty());
+}
+
+/// ✅ Test splitting a span from Dec 2024 into Feb 2025 by year
+#[test]
+fn test_split_by_year() {
+    let start = DecimalTime::new(2024, 350, 0.5); // Dec 15th 2024
+    let end = DecimalTime::new(2025, 45, 0.25); // Feb 14th 2025
+    let new_year = DecimalTime::new(2025, 1, 0.0);
+
+    assert_eq!(
+        DecimalTime::split_by_year(&start, &end),
+        vec![(2024, start, new_year), (2025, new_year, end)]
+    );
+}
+
+/// ✅ Test splitting spans inside one year, across several years, and empty spans
+#[test]
+fn test_split_by_year_edges() {
+    let a = DecimalTime::new(2025, 10, 0.0);
+    let b = DecimalTime::new(2025, 20, 0.0);
+    assert_eq!(DecimalTime::split_by_year(&a, &b), vec![(2025, a, b)]);
+    assert!(DecimalTime::split_by_year(&b, &a).is_empty());
+
+    let to_new_year = DecimalTime::new(2026, 1, 0.0);
+    assert_eq!(DecimalTime::split_by_year(&a, &to_new_year).len(), 1);
+
+    let years: Vec<i32> = DecimalTime::split_by_year(&a, &DecimalTime::new(2028, 5, 0.5))
+        .into_iter()
+        .map(|(year, _, _)| year)
+        .collect();
+    assert_eq!(years, vec![2025, 2026, 2027, 2028]);
+}
+
+/// ✅ Test `bounds` finds the earliest and latest instants in one pass
+#[test]
+fn test_bounds() {
+    let times = [
+        DecimalTime::new(2025, 74, 0.1),
+        DecimalTime::new(2024, 366, 0.9),
+        DecimalTime::new(2025, 74, 0.9),
+        DecimalTime::new(2025, 1, 0.0),
+    ];
+
+    assert_eq!(
+        DecimalTime::bounds(times),
+        Some((DecimalTime::new(2024, 366, 0.9), DecimalTime::new(2025, 74, 0.9)))
+    );
+    assert_eq!(
+        DecimalTime::bounds([times[0]]),
+        Some((times[0], times[0]))
+    );
+}
+
+/// ✅ Test `bounds` of an empty iterator is `None`
+#[test]
+fn test_bounds_empty() {
+    assert_eq!(DecimalTime::bounds(core::iter::empty()), None);
+}
+
+/// ✅ Test `contains` is half-open
+#[test]
+fn test_range_contains() {
+    let business_hours = DecimalTimeRange::new(DecimalTime::new(2025, 73, 0.375), DecimalTime::new(2025, 73, 0.75));
+
+    assert!(business_hours.contains(&DecimalTime::new(2025, 73, 0.375)));
+    assert!(business_hours.contains(&DecimalTime::new(2025, 73, 0.5)));
+    assert!(!business_hours.contains(&DecimalTime::new(2025, 73, 0.75)));
+    assert!(!business_hours.contains(&DecimalTime::new(2025, 74, 0.5)));
+}
+
+/// ✅ Test `overlaps` for overlapping, touching and empty ranges
+#[test]
+fn test_range_overlaps() {
+    let t = |frac| DecimalTime::new(2025, 73, frac);
+    let morning = DecimalTimeRange::new(t(0.25), t(0.5));
+
+    assert!(morning.overlaps(&DecimalTimeRange::new(t(0.4), t(0.6))));
+    assert!(DecimalTimeRange::new(t(0.4), t(0.6)).overlaps(&morning));
+    assert!(morning.overlaps(&DecimalTimeRange::new(t(0.0), t(0.9))));
+    assert!(!morning.overlaps(&DecimalTimeRange::new(t(0.5), t(0.75))));
+    assert!(!morning.overlaps(&DecimalTimeRange::new(t(0.3), t(0.3))));
+}
+
+/// ✅ Test `resample_to_grid` assigns the nearest sample within half a step
+#[test]
+fn test_resample_to_grid() {
+    let t = |frac| DecimalTime::new(2025, 73, frac);
+    let samples = [t(0.02), t(0.26), t(0.3), t(0.9)];
+
+    let grid = DecimalTime::resample_to_grid(&samples, DecimalDuration::from_decimal_days(0.25), &t(0.0), &DecimalTime::new(2025, 74, 0.0));
+
+    assert_eq!(
+        grid,
+        vec![
+            (t(0.0), Some(0)),
+            (t(0.25), Some(1)),
+            (t(0.5), None),
+            (t(0.75), None),
+            (DecimalTime::new(2025, 74, 0.0), Some(3)),
+        ]
+    );
+}
+
+/// ❌ Test `resample_to_grid` rejects a non-positive step
+#[test]
+#[should_panic(expected = "`step` must be positive")]
+fn test_resample_to_grid_zero_step() {
+    let t = DecimalTime::new(2025, 73, 0.0);
+    DecimalTime::resample_to_grid(&[t], DecimalDuration::ZERO, &t, &t);
+}
+
+/// ✅ Test `duration_between_utc` for a valid pair
+#[test]
+fn test_duration_between_utc() {
+    let a = DecimalTime::new(2024, 366, 0.75);
+    let b = DecimalTime::new(2025, 1, 0.25);
+
+    assert_eq!(DecimalTime::duration_between_utc(&a, &b), Some(DecimalDuration::from_decimal_days(0.5)));
+    assert_eq!(DecimalTime::duration_between_utc(&b, &a), Some(DecimalDuration::from_decimal_days(-0.5)));
+}
+
+/// ❌ Test `duration_between_utc` is `None` for day 366 of a non-leap year
+#[test]
+fn test_duration_between_utc_invalid() {
+    let valid = DecimalTime::new(2025, 73, 0.5);
+    let invalid = DecimalTime::new_unchecked(2025, 366, 0.5);
+
+    assert_eq!(DecimalTime::duration_between_utc(&valid, &invalid), None);
+    assert_eq!(DecimalTime::duration_between_utc(&invalid, &valid), None);
+}
+
+/// ✅ Test `lerp` between two times across a year boundary
+#[test]
+fn test_lerp() {
+    let a = DecimalTime::new(2024, 366, 0.5);
+    let b = DecimalTime::new(2025, 2, 0.5);
+
+    assert_eq!(DecimalTime::lerp(&a, &b, 0.0), a);
+    assert_eq!(DecimalTime::lerp(&a, &b, 1.0), b);
+    assert_eq!(DecimalTime::lerp(&a, &b, 0.5), DecimalTime::new(2025, 1, 0.5));
+    assert_eq!(DecimalTime::lerp(&a, &b, 0.25), DecimalTime::new(2025, 1, 0.0));
+    assert_eq!(DecimalTime::lerp(&b, &a, 0.25), DecimalTime::new(2025, 2, 0.0));
+}
+
+/// ✅ Test `lerp` extrapolates for `t` outside [0,1]
+#[test]
+fn test_lerp_extrapolates() {
+    let a = DecimalTime::new(2025, 73, 0.0);
+    let b = DecimalTime::new(2025, 74, 0.0);
+
+    assert_eq!(DecimalTime::lerp(&a, &b, 2.0), DecimalTime::new(2025, 75, 0.0));
+    assert_eq!(DecimalTime::lerp(&a, &b, -0.5), DecimalTime::new(2025, 72, 0.5));
+}
+
+/// ❌ Test `lerp` panics on a non-finite `t`
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_lerp_non_finite() {
+    let a = DecimalTime::new(2025, 73, 0.0);
+    DecimalTime::lerp(&a, &a, f64::NAN);
+}
+
+/// ✅ Test `mean_time_of_day` wraps around midnight
+#[test]
+fn test_mean_time_of_day() {
+    let t = |frac| DecimalTime::new(2025, 73, frac);
+
+    // The mean is 0.0 up to rounding, which may land just below 1.0
+    let around_midnight = DecimalTime::mean_time_of_day(&[t(0.99), t(0.01)]).unwrap();
+    assert!(around_midnight.min(1.0 - around_midnight) < 1e-9, "{}", around_midnight);
+
+    let morning = DecimalTime::mean_time_of_day(&[t(0.2), t(0.3), DecimalTime::new(2025, 74, 0.25)]).unwrap();
+    assert!((morning - 0.25).abs() < 1e-9);
+
+    let late = DecimalTime::mean_time_of_day(&[t(0.9), t(0.8)]).unwrap();
+    assert!((late - 0.85).abs() < 1e-9);
+}
+
+/// ❌ Test `mean_time_of_day` is `None` for empty or cancelling input
+#[test]
+fn test_mean_time_of_day_undefined() {
+    let t = |frac| DecimalTime::new(2025, 73, frac);
+
+    assert_eq!(DecimalTime::mean_time_of_day(&[]), None);
+    assert_eq!(DecimalTime::mean_time_of_day(&[t(0.25), t(0.75)]), None);
+    assert_eq!(DecimalTime::mean_time_of_day(&[t(0.0), t(1.0 / 3.0), t(2.0 / 3.0)]), None);
+}
+
+/// ✅ Test `until_next_midnight` and `since_last_midnight`
+#[test]
+fn test_midnight_durations() {
+    let dec = DecimalTime::new(2025, 73, 0.75);
+
+    assert_eq!(dec.until_next_midnight(), DecimalDuration::from_decimal_days(0.25));
+    assert_eq!(dec.since_last_midnight(), DecimalDuration::from_decimal_days(0.75));
+    assert_eq!(dec + dec.until_next_midnight(), DecimalTime::new(2025, 74, 0.0));
+    assert_eq!(dec - dec.since_last_midnight(), DecimalTime::new(2025, 73, 0.0));
+
+    let midnight = DecimalTime::new(2025, 73, 0.0);
+    assert_eq!(midnight.until_next_midnight(), DecimalDuration::from_decimal_days(1.0));
+    assert_eq!(midnight.since_last_midnight(), DecimalDuration::ZERO);
+}
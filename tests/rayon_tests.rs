@@ -0,0 +1,24 @@
+#![cfg(feature = "rayon")]
+
+use chrono::{Duration, TimeZone, Utc};
+use decimal_time::DecimalTime;
+
+/// ✅ Test parallel conversions match the sequential ones and keep order
+#[test]
+fn test_par_conversions_match_sequential() {
+    let start = Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap();
+    let datetimes: Vec<_> = (0..10_000).map(|i| start + Duration::seconds(i * 37)).collect();
+
+    let times = DecimalTime::par_from_datetimes_utc(&datetimes);
+    let sequential: Vec<_> = datetimes.iter().map(|dt| DecimalTime::from_datetime_utc(*dt)).collect();
+    assert_eq!(times, sequential);
+
+    assert_eq!(DecimalTime::par_to_datetimes_utc(&times), datetimes);
+}
+
+/// ✅ Test empty batches
+#[test]
+fn test_par_conversions_empty() {
+    assert!(DecimalTime::par_from_datetimes_utc(&[]).is_empty());
+    assert!(DecimalTime::par_to_datetimes_utc(&[]).is_empty());
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "republican")]
+
+use decimal_time::DecimalTime;
+
+/// ✅ Test months and décade day names inside the twelve months
+#[test]
+fn test_republican_months() {
+    let first = DecimalTime::new(2025, 1, 0.5);
+    assert_eq!(first.republican_month(), "Vendémiaire");
+    assert_eq!(first.republican_day_name(), "Primidi");
+
+    let tenth = DecimalTime::new(2025, 30, 0.5);
+    assert_eq!(tenth.republican_month(), "Vendémiaire");
+    assert_eq!(tenth.republican_day_name(), "Décadi");
+
+    let dec = DecimalTime::new(2025, 73, 0.5);
+    assert_eq!(dec.republican_month(), "Frimaire");
+    assert_eq!(dec.republican_day_name(), "Tridi");
+
+    let last = DecimalTime::new(2025, 360, 0.5);
+    assert_eq!(last.republican_month(), "Fructidor");
+    assert_eq!(last.republican_day_name(), "Décadi");
+}
+
+/// ✅ Test the complementary days, including the leap-year sixth day
+#[test]
+fn test_republican_complementary_days() {
+    let first = DecimalTime::new(2025, 361, 0.5);
+    assert_eq!(first.republican_month(), "Sansculottides");
+    assert_eq!(first.republican_day_name(), "Jour de la vertu");
+
+    assert_eq!(DecimalTime::new(2025, 365, 0.5).republican_day_name(), "Jour des récompenses");
+
+    let leap_day = DecimalTime::new(2024, 366, 0.5);
+    assert_eq!(leap_day.republican_month(), "Sansculottides");
+    assert_eq!(leap_day.republican_day_name(), "Jour de la révolution");
+}
+
+/// ❌ Test day 366 of a non-leap year is rejected
+#[test]
+#[should_panic(expected = "Invalid day_of_year=366 for year=2025")]
+fn test_republican_invalid_day() {
+    DecimalTime::new_unchecked(2025, 366, 0.5).republican_day_name();
+}
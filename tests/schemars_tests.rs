@@ -0,0 +1,109 @@
+#![cfg(feature = "schemars")]
+
+use chrono::{DateTime, Utc};
+use decimal_time::{DecimalDuration, DecimalTime, DecimalTimeRange};
+use regex::Regex;
+use schemars::{schema_for, JsonSchema};
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Serialize, JsonSchema)]
+struct Event {
+    at: DecimalTime,
+    #[serde(with = "decimal_time::serde::string")]
+    #[schemars(schema_with = "decimal_time::serde::string::json_schema")]
+    compact: DecimalTime,
+    #[serde(with = "decimal_time::serde::chrono_as_decimal")]
+    #[schemars(schema_with = "decimal_time::serde::chrono_as_decimal::json_schema")]
+    created: DateTime<Utc>,
+}
+
+/// ✅ Test the struct form's schema documents each field's range
+#[test]
+fn test_schema_struct_repr() {
+    let schema = schema_for!(DecimalTime);
+    let properties = &schema.as_value()["properties"];
+
+    assert_eq!(schema.as_value()["type"], "object");
+    assert_eq!(properties["year"]["minimum"], DecimalTime::MIN_YEAR);
+    assert_eq!(properties["year"]["maximum"], DecimalTime::MAX_YEAR);
+    assert_eq!(properties["day_of_year"]["minimum"], 1);
+    assert_eq!(properties["day_of_year"]["maximum"], 366);
+    assert_eq!(properties["decimal_day"]["exclusiveMaximum"], 1);
+    assert_eq!(schema.as_value()["required"], json!(["year", "day_of_year", "decimal_day"]));
+}
+
+/// ✅ Test annotated string fields get the string schema, and the pattern
+/// matches what the string form writes
+#[test]
+fn test_schema_string_fields() {
+    let schema = schema_for!(Event);
+    let properties = &schema.as_value()["properties"];
+
+    assert_eq!(properties["at"]["$ref"], "#/$defs/DecimalTime");
+    for field in ["compact", "created"] {
+        assert_eq!(properties[field]["type"], "string");
+        assert!(properties[field]["description"].as_str().unwrap().contains("2025-100.5"));
+    }
+
+    let event = Event {
+        at: DecimalTime::new(2025, 100, 0.5),
+        compact: DecimalTime::new(-44, 75, 0.0),
+        created: DecimalTime::new(2025, 100, 0.5).to_datetime_utc(),
+    };
+    let json = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["compact"], "-44-075");
+    assert_eq!(json["created"], "2025-100.5");
+
+    for field in ["compact", "created"] {
+        let pattern = Regex::new(properties[field]["pattern"].as_str().unwrap()).unwrap();
+        assert!(pattern.is_match(json[field].as_str().unwrap()), "{}", field);
+    }
+}
+
+/// ✅ Test the string pattern matches the string form of awkward values and
+/// rejects strings the string form never writes
+#[test]
+fn test_schema_string_pattern_matches_output() {
+    #[derive(Serialize)]
+    struct Wrapper(#[serde(with = "decimal_time::serde::string")] DecimalTime);
+
+    let schema = schema_for!(Event);
+    let pattern = schema.as_value()["properties"]["compact"]["pattern"].as_str().unwrap();
+    let pattern = Regex::new(pattern).unwrap();
+
+    let chrono_derived = DecimalTime::from_datetime_utc(
+        chrono::TimeZone::with_ymd_and_hms(&Utc, 2025, 3, 14, 13, 37, 42).unwrap(),
+    );
+    for dec in [
+        chrono_derived,
+        DecimalTime::new(1, 1, 0.0),
+        DecimalTime::new(-44, 366, 0.999_999_999_999),
+        DecimalTime::max_value(),
+        DecimalTime::min_value(),
+    ] {
+        let json = serde_json::to_value(Wrapper(dec)).unwrap();
+        let written = json.as_str().unwrap();
+        assert!(pattern.is_match(written), "{}", written);
+    }
+
+    for invalid in ["2025/100.5", "2025-100.5x", "year-100", "2025-1000"] {
+        assert!(!pattern.is_match(invalid), "{}", invalid);
+    }
+}
+
+/// ✅ Test durations and ranges get their own schemas
+#[test]
+fn test_schema_duration_and_range() {
+    let duration = schema_for!(DecimalDuration);
+    assert_eq!(duration.as_value()["type"], "integer");
+    assert_eq!(duration.as_value()["format"], "int64");
+
+    let range = schema_for!(DecimalTimeRange);
+    let properties = &range.as_value()["properties"];
+    assert_eq!(range.as_value()["type"], "object");
+    assert_eq!(properties["start"]["$ref"], "#/$defs/DecimalTime");
+    assert_eq!(properties["end"]["$ref"], "#/$defs/DecimalTime");
+    assert_eq!(range.as_value()["required"], json!(["start", "end"]));
+    assert_eq!(range.as_value()["$defs"]["DecimalTime"]["type"], "object");
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "serde")]
+
+use decimal_time::{DecimalDuration, DecimalTime, DecimalTimeRange};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Event {
+    #[serde(with = "decimal_time::serde::string")]
+    compact: DecimalTime,
+    #[serde(with = "decimal_time::serde::struct_repr")]
+    explicit: DecimalTime,
+    default: DecimalTime,
+}
+
+/// ✅ Test the struct and string representations round-trip through JSON
+#[test]
+fn test_serde_representations() {
+    let event = Event {
+        compact: DecimalTime::new(2025, 100, 0.5),
+        explicit: DecimalTime::new(2025, 73, 0.25),
+        default: DecimalTime::new(-44, 75, 0.123_456_789),
+    };
+
+    let json = serde_json::to_string(&event).unwrap();
+    assert_eq!(
+        json,
+        r#"{"compact":"2025-100.5","explicit":{"year":2025,"day_of_year":73,"decimal_day":0.25},"default":{"year":-44,"day_of_year":75,"decimal_day":0.123456789}}"#
+    );
+    assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+}
+
+/// ✅ Test the string representation keeps the full fraction and reads `Display` output
+#[test]
+fn test_serde_string_precision() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "decimal_time::serde::string")] DecimalTime);
+
+    let dec = DecimalTime::new(2025, 1, 0.123_456_789_012_345);
+    let json = serde_json::to_string(&Wrapper(dec)).unwrap();
+    assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), Wrapper(dec));
+
+    let canonical = format!("\"{}\"", DecimalTime::new(2025, 100, 0.5));
+    assert_eq!(serde_json::from_str::<Wrapper>(&canonical).unwrap(), Wrapper(DecimalTime::new(2025, 100, 0.5)));
+}
+
+/// ❌ Test deserializing rejects out-of-range fields and malformed strings
+#[test]
+fn test_serde_invalid() {
+    let err = serde_json::from_str::<DecimalTime>(r#"{"year":2025,"day_of_year":366,"decimal_day":0.5}"#).unwrap_err();
+    assert!(err.to_string().contains("`day_of_year` must be in [1..=365] for year 2025"));
+
+    assert!(serde_json::from_str::<DecimalTime>(r#"{"year":2025,"day_of_year":1,"decimal_day":1.0}"#).is_err());
+    assert!(serde_json::from_str::<DecimalTime>(r#"{"year":2025,"day_of_year":1}"#).is_err());
+
+    #[derive(Debug, Deserialize)]
+    struct Wrapper(#[serde(with = "decimal_time::serde::string")] #[allow(dead_code)] DecimalTime);
+    assert!(serde_json::from_str::<Wrapper>(r#""2025/100.5""#).is_err());
+    assert!(serde_json::from_str::<Wrapper>("42").is_err());
+}
+
+/// ✅ Test `chrono_as_decimal` writes `DateTime<Utc>` fields as decimal time
+#[test]
+fn test_serde_chrono_as_decimal() {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "decimal_time::serde::chrono_as_decimal")]
+        created: DateTime<Utc>,
+    }
+
+    let record = Record { created: Utc.with_ymd_and_hms(2025, 4, 10, 12, 0, 0).unwrap() };
+    let json = serde_json::to_string(&record).unwrap();
+    assert_eq!(json, r#"{"created":"2025-100.5"}"#);
+    assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+
+    let precise = Record { created: Utc.with_ymd_and_hms(2025, 4, 10, 7, 13, 59).unwrap() + chrono::Duration::microseconds(123_456) };
+    let json = serde_json::to_string(&precise).unwrap();
+    assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), precise);
+
+    assert!(serde_json::from_str::<Record>(r#"{"created":"2025-366.5"}"#).is_err());
+}
+
+/// ❌ Test `chrono_as_decimal` fails instead of panicking on a leap second
+#[test]
+fn test_serde_chrono_as_decimal_leap_second() {
+    use chrono::{DateTime, NaiveDate, Utc};
+
+    #[derive(Serialize)]
+    struct Record {
+        #[serde(with = "decimal_time::serde::chrono_as_decimal")]
+        created: DateTime<Utc>,
+    }
+
+    let leap = NaiveDate::from_ymd_opt(2016, 12, 31).unwrap().and_hms_milli_opt(23, 59, 59, 1_500).unwrap().and_utc();
+    let err = serde_json::to_string(&Record { created: leap }).unwrap_err();
+    assert!(err.to_string().contains("decimal_day"), "{}", err);
+}
+
+/// ✅ Test durations round-trip as whole microseconds and ranges as `{start, end}`
+#[test]
+fn test_serde_duration_and_range() {
+    let duration = DecimalDuration::from_decimal_days(-1.5);
+    let json = serde_json::to_string(&duration).unwrap();
+    assert_eq!(json, "-129600000000");
+    assert_eq!(serde_json::from_str::<DecimalDuration>(&json).unwrap(), duration);
+
+    let range = DecimalTimeRange::new(DecimalTime::new(2025, 73, 0.25), DecimalTime::new(2025, 74, 0.0));
+    let json = serde_json::to_string(&range).unwrap();
+    assert_eq!(
+        json,
+        r#"{"start":{"year":2025,"day_of_year":73,"decimal_day":0.25},"end":{"year":2025,"day_of_year":74,"decimal_day":0.0}}"#
+    );
+    assert_eq!(serde_json::from_str::<DecimalTimeRange>(&json).unwrap(), range);
+}
+
+/// ❌ Test deserializing a range validates each endpoint
+#[test]
+fn test_serde_range_invalid() {
+    let json = r#"{"start":{"year":2025,"day_of_year":366,"decimal_day":0.0},"end":{"year":2026,"day_of_year":1,"decimal_day":0.0}}"#;
+    assert!(serde_json::from_str::<DecimalTimeRange>(json).is_err());
+    assert!(serde_json::from_str::<DecimalDuration>("1.5").is_err());
+}
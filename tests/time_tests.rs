@@ -0,0 +1,41 @@
+#![cfg(feature = "time")]
+
+use decimal_time::DecimalTime;
+use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+fn noon_2025_03_14() -> PrimitiveDateTime {
+    PrimitiveDateTime::new(
+        Date::from_calendar_date(2025, Month::March, 14).unwrap(),
+        Time::from_hms(12, 0, 0).unwrap(),
+    )
+}
+
+/// ✅ Test conversion from `time::PrimitiveDateTime`
+#[test]
+fn test_from_primitive_datetime() {
+    let dec = DecimalTime::from(noon_2025_03_14());
+
+    assert_eq!(dec, DecimalTime::new(2025, 73, 0.5));
+}
+
+/// ✅ Test conversion from `time::OffsetDateTime` normalizes to UTC
+#[test]
+fn test_from_offset_datetime() {
+    let offset = UtcOffset::from_hms(6, 0, 0).unwrap();
+    let dt = noon_2025_03_14().assume_offset(offset);
+
+    assert_eq!(DecimalTime::from(dt), DecimalTime::new(2025, 73, 0.25));
+}
+
+/// ✅ Test round trip through `to_offset_datetime`
+#[test]
+fn test_to_offset_datetime_round_trip() {
+    let dt = PrimitiveDateTime::new(
+        Date::from_calendar_date(2025, Month::March, 14).unwrap(),
+        Time::from_hms_micro(15, 9, 26, 535_897).unwrap(),
+    )
+    .assume_utc();
+
+    let back = DecimalTime::from(dt).to_offset_datetime();
+    assert_eq!(back, dt);
+}